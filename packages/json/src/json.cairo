@@ -1,4 +1,6 @@
 use alexandria_math::decimal::{DECIMAL_SCALE, Decimal, DecimalTrait};
+use alexandria_math::i257::{I257Impl, i257};
+use core::num::traits::{CheckedAdd, CheckedMul};
 
 #[derive(Drop, Clone, PartialEq, Debug)]
 pub enum JsonValue {
@@ -6,6 +8,7 @@ pub enum JsonValue {
     Array: Array<JsonValue>,
     String: ByteArray,
     Number: felt252,
+    SignedNumber: i128,
     Decimal: Decimal,
     Bool: bool,
     Null,
@@ -22,6 +25,7 @@ pub enum JsonError {
     MissingField,
     TypeMismatch,
     DuplicateKey,
+    OutOfRange,
 }
 
 #[derive(Drop)]
@@ -396,6 +400,18 @@ pub impl JsonValueHelperImpl of JsonValueHelper {
         }
     }
 
+    /// Value of the first of `keys` present in the object, `MissingField` if none is.
+    fn get_any_object_field(self: @JsonValue, keys: Span<ByteArray>) -> Result<JsonValue, JsonError> {
+        for key in keys {
+            match self.get_object_field(key.clone()) {
+                Result::Ok(value) => { return Result::Ok(value); },
+                Result::Err(JsonError::MissingField) => {},
+                Result::Err(error) => { return Result::Err(error); },
+            }
+        }
+        Result::Err(JsonError::MissingField)
+    }
+
     fn as_string(self: @JsonValue) -> Result<ByteArray, JsonError> {
         match self {
             JsonValue::String(s) => Result::Ok(s.clone()),
@@ -406,6 +422,7 @@ pub impl JsonValueHelperImpl of JsonValueHelper {
     fn as_number(self: @JsonValue) -> Result<felt252, JsonError> {
         match self {
             JsonValue::Number(n) => Result::Ok(*n),
+            JsonValue::SignedNumber(n) => Result::Ok((*n).into()),
             _ => Result::Err(JsonError::TypeMismatch),
         }
     }
@@ -417,6 +434,16 @@ pub impl JsonValueHelperImpl of JsonValueHelper {
         }
     }
 
+    /// Converts a number to an integer type, e.g. `u8`, `i64` or `ContractAddress`.
+    /// Fails with `OutOfRange` if the number doesn't fit in the type.
+    fn as_int<T, +TryInto<felt252, T>>(self: @JsonValue) -> Result<T, JsonError> {
+        let n = self.as_number()?;
+        match n.try_into() {
+            Option::Some(value) => Result::Ok(value),
+            Option::None => Result::Err(JsonError::OutOfRange),
+        }
+    }
+
     fn as_decimal(self: @JsonValue) -> Result<Decimal, JsonError> {
         match self {
             JsonValue::Decimal(d) => Result::Ok(*d),
@@ -462,6 +489,7 @@ pub impl JsonValueHelperImpl of JsonValueHelper {
                 result
             },
             JsonValue::Number(n) => { convert_felt_to_string(*n) },
+            JsonValue::SignedNumber(n) => { convert_signed_to_string(*n) },
             JsonValue::Decimal(d) => { d.to_string() },
             JsonValue::Bool(b) => { if *b {
                 "true"
@@ -524,17 +552,37 @@ fn escape_json_string(s: @ByteArray) -> ByteArray {
     result
 }
 
+/// Returns the absolute value of `n` if it's a negative number, i.e. a felt just below the
+/// prime such as the ones parsed from `-5` or converted from a signed integer.
+fn negative_felt_abs(n: felt252) -> Option<u128> {
+    (-n).try_into()
+}
+
 /// Convert a felt252 number to its string representation
 /// Handles any felt252 value by converting to u256 and processing digits
 fn convert_felt_to_string(n: felt252) -> ByteArray {
+    convert_u256_to_string(n.into())
+}
+
+/// Convert a signed integer to its string representation, with a minus sign if it's negative
+fn convert_signed_to_string(n: i128) -> ByteArray {
+    let felt: felt252 = n.into();
+    if n < 0 {
+        let mut result: ByteArray = "-";
+        result.append(@convert_felt_to_string(-felt));
+        result
+    } else {
+        convert_felt_to_string(felt)
+    }
+}
+
+/// Convert a u256 number to its decimal string representation
+fn convert_u256_to_string(value: u256) -> ByteArray {
     // Handle zero specially
-    if n == 0 {
+    if value == 0 {
         return "0";
     }
 
-    // Convert felt252 to u256 for processing
-    let value: u256 = n.into();
-
     // Convert u256 to string by extracting digits
     let mut digits: Array<u8> = array![];
     let mut temp_value = value;
@@ -557,6 +605,87 @@ fn convert_felt_to_string(n: felt252) -> ByteArray {
     result
 }
 
+/// Parses an optionally negative decimal string, e.g. `-12`, into an `i257`.
+/// Fails with `OutOfRange` if the absolute value doesn't fit in a `u256`.
+fn parse_i257(s: @ByteArray) -> Result<i257, JsonError> {
+    let is_negative = s.len() > 0 && s.at(0).unwrap() == '-';
+    let mut i = if is_negative {
+        1
+    } else {
+        0
+    };
+    if i == s.len() {
+        return Result::Err(JsonError::InvalidNumber);
+    }
+
+    let mut abs: u256 = 0;
+    while i < s.len() {
+        let ch = s.at(i).unwrap();
+        if ch < '0' || ch > '9' {
+            return Result::Err(JsonError::InvalidNumber);
+        }
+        let digit: u256 = (ch - '0').into();
+        abs = match abs.checked_mul(10) {
+            Option::Some(value) => match value.checked_add(digit) {
+                Option::Some(value) => value,
+                Option::None => { return Result::Err(JsonError::OutOfRange); },
+            },
+            Option::None => { return Result::Err(JsonError::OutOfRange); },
+        };
+        i += 1;
+    }
+
+    Result::Ok(I257Impl::new(abs, is_negative))
+}
+
+/// Serializes an `i257` as a number if it fits in a `u128` or an `i128`, and as a decimal
+/// string, e.g. `"-340282366920938463463374607431768211456"`, otherwise.
+impl I257JsonSerialize of JsonSerialize<i257> {
+    fn to_json(self: @i257) -> JsonValue {
+        let value = *self;
+        let small_abs: Option<u128> = value.abs().try_into();
+        if let Option::Some(abs) = small_abs {
+            let abs: felt252 = abs.into();
+            if !value.is_negative() {
+                return JsonValue::Number(abs);
+            }
+            let signed: Option<i128> = (-abs).try_into();
+            if let Option::Some(signed) = signed {
+                return JsonValue::SignedNumber(signed);
+            }
+        }
+
+        let mut result: ByteArray = if value.is_negative() {
+            "-"
+        } else {
+            ""
+        };
+        result.append(@convert_u256_to_string(value.abs()));
+        JsonValue::String(result)
+    }
+}
+
+/// Converts a felt to an `i257`, the felts just below the prime being negative numbers.
+fn felt_to_i257(n: felt252) -> i257 {
+    match negative_felt_abs(n) {
+        Option::Some(abs) => I257Impl::new(abs.into(), true),
+        Option::None => I257Impl::new(n.into(), false),
+    }
+}
+
+/// Deserializes an `i257` from a number, negative numbers being the felts just below the prime,
+/// or from a decimal string.
+impl I257JsonDeserialize of JsonDeserialize<i257> {
+    fn from_json(value: JsonValue) -> Result<i257, JsonError> {
+        match value {
+            JsonValue::Number(n) => Result::Ok(felt_to_i257(n)),
+            JsonValue::SignedNumber(n) => Result::Ok(felt_to_i257(n.into())),
+            JsonValue::String(s) => parse_i257(@s),
+            _ => Result::Err(JsonError::TypeMismatch),
+        }
+    }
+}
+
 pub fn deserialize_json<T, +JsonDeserialize<T>>(input: ByteArray) -> Result<T, JsonError> {
     let json_value = parse_json(input)?;
     JsonDeserialize::from_json(json_value)
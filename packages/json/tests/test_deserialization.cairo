@@ -1,5 +1,10 @@
-use alexandria_json::json::{JsonError, JsonValue, deserialize_json, parse_json, serialize_json};
+use alexandria_json::json::{
+    JsonDeserialize, JsonError, JsonSerialize, JsonValue, deserialize_json, parse_json,
+    serialize_json,
+};
 use alexandria_math::decimal::{DECIMAL_SCALE, Decimal, DecimalTrait};
+use alexandria_math::i257::{I257Impl, i257};
+use starknet::{ClassHash, ContractAddress, EthAddress};
 
 // Test struct with all supported types
 #[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
@@ -45,6 +50,14 @@ struct Price {
     currency: ByteArray,
 }
 
+// Test struct with nested struct fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
+struct Order {
+    id: felt252,
+    owner: User,
+    price: Price,
+}
+
 // Test struct with mixed Decimal and other types
 #[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
 struct Product {
@@ -55,6 +68,55 @@ struct Product {
     quantity: felt252,
 }
 
+// Test struct with integer and Starknet fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq)]
+struct Transfer {
+    nonce: u64,
+    fee: u8,
+    delta: i32,
+    index: usize,
+    recipient: ContractAddress,
+    token: EthAddress,
+    class_hash: ClassHash,
+    pnl: i257,
+}
+
+// Test struct with optional fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
+struct Listing {
+    title: ByteArray,
+    price: Option<u64>,
+    #[json(skip_if_none)]
+    note: Option<ByteArray>,
+    seller: Option<User>,
+}
+
+// Test struct with array and span fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
+struct Portfolio {
+    ids: Array<u64>,
+    hashes: Span<felt252>,
+    prices: Array<Decimal>,
+    holders: Array<Price>,
+    matrix: Array<Array<u8>>,
+}
+
+// Test struct with renamed, skipped, defaulted and aliased fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
+#[json(rename_all = "camelCase")]
+struct ApiUser {
+    user_id: u64,
+    display_name: ByteArray,
+    #[json(rename = "type")]
+    kind: ByteArray,
+    #[json(skip)]
+    cache: felt252,
+    #[json(default)]
+    login_count: u32,
+    #[json(alias = "mail", alias = "email_address")]
+    email: ByteArray,
+}
+
 #[test]
 fn test_deserialize_mixed_types() {
     // Create original data
@@ -624,3 +686,331 @@ fn test_json_error_deserialization_error_comprehensive() {
     let error = result.unwrap_err();
     assert!(error == JsonError::UnexpectedCharacter);
 }
+
+#[test]
+fn test_deserialize_nested_structs() {
+    let json_string =
+        "{\"id\": 7, \"owner\": {\"name\": \"Alice\", \"age\": 30, \"is_active\": true, \"tags\": [\"cairo\"]}, \"price\": {\"amount\": 19.99, \"currency\": \"USD\"}}";
+    let result: Result<Order, JsonError> = deserialize_json(json_string);
+
+    let order = result.unwrap();
+    assert!(order.id == 7);
+    assert!(order.owner == User { name: "Alice", age: 30, is_active: true, tags: array!["cairo"] });
+    assert!(order.price.currency == "USD");
+    assert!(order.price.amount.int_part() == 19);
+}
+
+#[test]
+fn test_nested_structs_roundtrip() {
+    let order = Order {
+        id: 1,
+        owner: User { name: "Bob", age: 41, is_active: false, tags: array![] },
+        price: Price { amount: DecimalTrait::from_int(5), currency: "EUR" },
+    };
+
+    let result: Result<Order, JsonError> = deserialize_json(serialize_json(@order));
+    assert!(result.unwrap() == order);
+}
+
+#[test]
+fn test_deserialize_nested_struct_errors() {
+    // The nested object misses a member
+    let missing_json = "{\"id\": 7, \"owner\": {\"name\": \"Alice\"}, \"price\": {\"amount\": 1, \"currency\": \"USD\"}}";
+    let result: Result<Order, JsonError> = deserialize_json(missing_json);
+    assert!(result.unwrap_err() == JsonError::MissingField);
+
+    // The nested value isn't an object
+    let mismatch_json = "{\"id\": 7, \"owner\": \"Alice\", \"price\": {\"amount\": 1, \"currency\": \"USD\"}}";
+    let result: Result<Order, JsonError> = deserialize_json(mismatch_json);
+    assert!(result.unwrap_err() == JsonError::TypeMismatch);
+}
+
+#[test]
+fn test_deserialize_integer_fields() {
+    let json_string =
+        "{\"nonce\": 18446744073709551615, \"fee\": 255, \"delta\": -42, \"index\": 3, \"recipient\": 4660, \"token\": 1, \"class_hash\": 2, \"pnl\": -99}";
+    let result: Result<Transfer, JsonError> = deserialize_json(json_string);
+
+    let transfer = result.unwrap();
+    assert!(transfer.nonce == 18446744073709551615);
+    assert!(transfer.fee == 255);
+    assert!(transfer.delta == -42);
+    assert!(transfer.index == 3);
+    assert!(transfer.recipient == 0x1234.try_into().unwrap());
+    assert!(transfer.token == 1.try_into().unwrap());
+    assert!(transfer.class_hash == 2.try_into().unwrap());
+    assert!(transfer.pnl == I257Impl::new(99, true));
+}
+
+#[test]
+fn test_integer_fields_roundtrip() {
+    let transfer = Transfer {
+        nonce: 0,
+        fee: 1,
+        delta: -2147483648,
+        index: 4294967295,
+        recipient: 5.try_into().unwrap(),
+        token: 6.try_into().unwrap(),
+        class_hash: 7.try_into().unwrap(),
+        pnl: I257Impl::new(0, false),
+    };
+
+    let result: Result<Transfer, JsonError> = deserialize_json(serialize_json(@transfer));
+    assert!(result.unwrap() == transfer);
+}
+
+#[test]
+fn test_deserialize_integer_out_of_range() {
+    // `fee` doesn't fit in `u8`
+    let json_string =
+        "{\"nonce\": 1, \"fee\": 256, \"delta\": 0, \"index\": 0, \"recipient\": 1, \"token\": 1, \"class_hash\": 1, \"pnl\": 0}";
+    let result: Result<Transfer, JsonError> = deserialize_json(json_string);
+    assert!(result.unwrap_err() == JsonError::OutOfRange);
+
+    // A negative `nonce` doesn't fit in `u64`
+    let json_string =
+        "{\"nonce\": -1, \"fee\": 0, \"delta\": 0, \"index\": 0, \"recipient\": 1, \"token\": 1, \"class_hash\": 1, \"pnl\": 0}";
+    let result: Result<Transfer, JsonError> = deserialize_json(json_string);
+    assert!(result.unwrap_err() == JsonError::OutOfRange);
+
+    // A string isn't a number
+    let json_string =
+        "{\"nonce\": \"1\", \"fee\": 0, \"delta\": 0, \"index\": 0, \"recipient\": 1, \"token\": 1, \"class_hash\": 1, \"pnl\": 0}";
+    let result: Result<Transfer, JsonError> = deserialize_json(json_string);
+    assert!(result.unwrap_err() == JsonError::TypeMismatch);
+}
+
+fn i257_roundtrip(value: i257) -> i257 {
+    JsonDeserialize::from_json(JsonSerialize::to_json(@value)).unwrap()
+}
+
+#[test]
+fn test_i257_boundaries() {
+    // Non-negative values that fit in a u128 are numbers
+    let max = I257Impl::new(0xffffffffffffffffffffffffffffffff, false);
+    assert!(
+        JsonSerialize::to_json(@max) == JsonValue::Number(0xffffffffffffffffffffffffffffffff),
+    );
+    assert!(i257_roundtrip(max) == max);
+
+    // Negative values that fit in an i128 are signed numbers
+    let min = I257Impl::new(0x80000000000000000000000000000000, true);
+    assert!(
+        JsonSerialize::to_json(@min) == JsonValue::SignedNumber(
+            -0x80000000000000000000000000000000,
+        ),
+    );
+    assert!(serialize_json(@min) == "-170141183460469231731687303715884105728");
+    assert!(i257_roundtrip(min) == min);
+
+    // Larger values are decimal strings
+    let below_min = I257Impl::new(0xffffffffffffffffffffffffffffffff, true);
+    assert!(
+        JsonSerialize::to_json(@below_min) == JsonValue::String(
+            "-340282366920938463463374607431768211455",
+        ),
+    );
+    assert!(i257_roundtrip(below_min) == below_min);
+
+    let below_min = I257Impl::new(0x100000000000000000000000000000000, true);
+    assert!(
+        JsonSerialize::to_json(@below_min) == JsonValue::String(
+            "-340282366920938463463374607431768211456",
+        ),
+    );
+    assert!(i257_roundtrip(below_min) == below_min);
+
+    let large = I257Impl::new(
+        0x8000000000000000000000000000000000000000000000000000000000000000, false,
+    );
+    let json_string = serialize_json(@large);
+    assert!(
+        json_string == "\"57896044618658097711785492504343953926634992332820282019728792003956564819968\"",
+    );
+    let result: Result<i257, JsonError> = deserialize_json(json_string);
+    assert!(result.unwrap() == large);
+}
+
+#[test]
+fn test_deserialize_i257_errors() {
+    // The absolute value doesn't fit in a u256
+    let too_large = JsonValue::String(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+    );
+    let result: Result<i257, JsonError> = JsonDeserialize::from_json(too_large);
+    assert!(result.unwrap_err() == JsonError::OutOfRange);
+
+    let result: Result<i257, JsonError> = JsonDeserialize::from_json(JsonValue::String("12a"));
+    assert!(result.unwrap_err() == JsonError::InvalidNumber);
+
+    let result: Result<i257, JsonError> = JsonDeserialize::from_json(JsonValue::String("-"));
+    assert!(result.unwrap_err() == JsonError::InvalidNumber);
+
+    let result: Result<i257, JsonError> = JsonDeserialize::from_json(JsonValue::Bool(true));
+    assert!(result.unwrap_err() == JsonError::TypeMismatch);
+}
+
+#[test]
+fn test_deserialize_option_fields() {
+    let json_string =
+        "{\"title\": \"Chair\", \"price\": 25, \"note\": \"used\", \"seller\": {\"name\": \"Bob\", \"age\": 41, \"is_active\": true, \"tags\": []}}";
+    let result: Result<Listing, JsonError> = deserialize_json(json_string);
+
+    let listing = result.unwrap();
+    assert!(listing.price == Option::Some(25));
+    assert!(listing.note == Option::Some("used"));
+    assert!(
+        listing.seller == Option::Some(User { name: "Bob", age: 41, is_active: true, tags: array![] }),
+    );
+}
+
+#[test]
+fn test_deserialize_option_fields_null_or_missing() {
+    // `null` and a missing key are both `None`
+    let json_string = "{\"title\": \"Table\", \"price\": null, \"seller\": null}";
+    let result: Result<Listing, JsonError> = deserialize_json(json_string);
+
+    let listing = result.unwrap();
+    assert!(listing.price == Option::None);
+    assert!(listing.note == Option::None);
+    assert!(listing.seller == Option::None);
+
+    let result: Result<Listing, JsonError> = deserialize_json("{\"title\": \"Table\"}");
+    assert!(result.unwrap().price == Option::None);
+
+    // A required field is still required, and a present value must match the type
+    let result: Result<Listing, JsonError> = deserialize_json("{\"price\": 1}");
+    assert!(result.unwrap_err() == JsonError::MissingField);
+    let result: Result<Listing, JsonError> = deserialize_json(
+        "{\"title\": \"Table\", \"price\": \"1\"}",
+    );
+    assert!(result.unwrap_err() == JsonError::TypeMismatch);
+}
+
+#[test]
+fn test_option_fields_roundtrip() {
+    let listing = Listing {
+        title: "Lamp", price: Option::Some(3), note: Option::None, seller: Option::None,
+    };
+
+    let result: Result<Listing, JsonError> = deserialize_json(serialize_json(@listing));
+    assert!(result.unwrap() == listing);
+}
+
+#[test]
+fn test_nested_option_fields_roundtrip() {
+    let listing = Listing {
+        title: "Desk",
+        price: Option::None,
+        note: Option::Some("oak"),
+        seller: Option::Some(
+            User { name: "Carol", age: 52, is_active: true, tags: array!["wood", "vintage"] },
+        ),
+    };
+
+    let result: Result<Listing, JsonError> = deserialize_json(serialize_json(@listing));
+    assert!(result.unwrap() == listing);
+
+    let order = Order {
+        id: 2,
+        owner: User { name: "Dave", age: 23, is_active: true, tags: array!["new"] },
+        price: Price { amount: DecimalTrait::from_int(12), currency: "GBP" },
+    };
+
+    let result: Result<Order, JsonError> = deserialize_json(serialize_json(@order));
+    assert!(result.unwrap() == order);
+}
+
+#[test]
+fn test_deserialize_array_fields() {
+    let json_string =
+        "{\"ids\": [1, 2], \"hashes\": [16], \"prices\": [1.5], \"holders\": [{\"amount\": 1, \"currency\": \"USD\"}], \"matrix\": [[1, 2], [], [3]]}";
+    let result: Result<Portfolio, JsonError> = deserialize_json(json_string);
+
+    let portfolio = result.unwrap();
+    assert!(portfolio.ids == array![1, 2]);
+    assert!(portfolio.hashes == array![16].span());
+    assert!(portfolio.prices == array![DecimalTrait::from_parts(1, 5)]);
+    assert!(
+        portfolio.holders == array![Price { amount: DecimalTrait::from_int(1), currency: "USD" }],
+    );
+    assert!(portfolio.matrix == array![array![1, 2], array![], array![3]]);
+}
+
+#[test]
+fn test_array_fields_roundtrip() {
+    let portfolio = Portfolio {
+        ids: array![],
+        hashes: array![1, 2, 3].span(),
+        prices: array![DecimalTrait::from_int(2), DecimalTrait::from_int(3)],
+        holders: array![],
+        matrix: array![array![255]],
+    };
+
+    let result: Result<Portfolio, JsonError> = deserialize_json(serialize_json(@portfolio));
+    assert!(result.unwrap() == portfolio);
+}
+
+#[test]
+fn test_deserialize_array_element_errors() {
+    // An element of a nested array doesn't fit in `u8`
+    let json_string =
+        "{\"ids\": [], \"hashes\": [], \"prices\": [], \"holders\": [], \"matrix\": [[256]]}";
+    let result: Result<Portfolio, JsonError> = deserialize_json(json_string);
+    assert!(result.unwrap_err() == JsonError::OutOfRange);
+
+    // An element has the wrong type
+    let json_string =
+        "{\"ids\": [true], \"hashes\": [], \"prices\": [], \"holders\": [], \"matrix\": []}";
+    let result: Result<Portfolio, JsonError> = deserialize_json(json_string);
+    assert!(result.unwrap_err() == JsonError::TypeMismatch);
+}
+
+#[test]
+fn test_deserialize_renamed_fields() {
+    let json_string =
+        "{\"userId\": 1, \"displayName\": \"Alice\", \"type\": \"admin\", \"cache\": 42, \"loginCount\": 3, \"email\": \"alice@example.com\"}";
+    let result: Result<ApiUser, JsonError> = deserialize_json(json_string);
+
+    let user = result.unwrap();
+    assert!(user.user_id == 1);
+    assert!(user.display_name == "Alice");
+    assert!(user.kind == "admin");
+    // Skipped fields are never read
+    assert!(user.cache == 0);
+    assert!(user.login_count == 3);
+    assert!(user.email == "alice@example.com");
+}
+
+#[test]
+fn test_deserialize_default_and_alias_fields() {
+    // `loginCount` is missing and `email` is given under an alias
+    let json_string =
+        "{\"userId\": 1, \"displayName\": \"Alice\", \"type\": \"admin\", \"email_address\": \"alice@example.com\"}";
+    let result: Result<ApiUser, JsonError> = deserialize_json(json_string);
+
+    let user = result.unwrap();
+    assert!(user.login_count == 0);
+    assert!(user.email == "alice@example.com");
+
+    // The Cairo field names aren't keys anymore
+    let json_string =
+        "{\"user_id\": 1, \"displayName\": \"Alice\", \"type\": \"admin\", \"email\": \"alice@example.com\"}";
+    let result: Result<ApiUser, JsonError> = deserialize_json(json_string);
+    assert!(result.unwrap_err() == JsonError::MissingField);
+}
+
+#[test]
+fn test_renamed_fields_roundtrip() {
+    let user = ApiUser {
+        user_id: 7,
+        display_name: "Bob",
+        kind: "user",
+        cache: 0,
+        login_count: 0,
+        email: "bob@example.com",
+    };
+
+    let result: Result<ApiUser, JsonError> = deserialize_json(serialize_json(@user));
+    assert!(result.unwrap() == user);
+}
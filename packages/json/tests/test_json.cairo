@@ -1,4 +1,4 @@
-use alexandria_json::json::{JsonError, JsonValue, parse_json};
+use alexandria_json::json::{JsonError, JsonValue, JsonValueHelper, parse_json};
 use alexandria_math::decimal::DecimalTrait;
 
 #[test]
@@ -547,3 +547,48 @@ fn test_parse_signed_decimal_in_object() {
         panic!("Expected object");
     }
 }
+
+#[test]
+fn test_as_int() {
+    let value = JsonValue::Number(-5);
+    assert!(value.as_int() == Result::<i8, JsonError>::Ok(-5));
+    assert!(value.as_int() == Result::<u8, JsonError>::Err(JsonError::OutOfRange));
+    assert!(JsonValue::Number(300).as_int() == Result::<u8, JsonError>::Err(JsonError::OutOfRange));
+    assert!(JsonValue::Bool(true).as_int() == Result::<u8, JsonError>::Err(JsonError::TypeMismatch));
+
+    let value = JsonValue::SignedNumber(-5);
+    assert!(value.as_int() == Result::<i8, JsonError>::Ok(-5));
+    assert!(value.as_int() == Result::<u8, JsonError>::Err(JsonError::OutOfRange));
+    assert!(value.as_number() == Result::Ok(-5));
+}
+
+#[test]
+fn test_signed_number_to_json_string() {
+    assert!(JsonValue::SignedNumber(-42).to_json_string() == "-42");
+    assert!(JsonValue::SignedNumber(42).to_json_string() == "42");
+    assert!(JsonValue::SignedNumber(0).to_json_string() == "0");
+    assert!(
+        JsonValue::SignedNumber(-0x80000000000000000000000000000000)
+            .to_json_string() == "-170141183460469231731687303715884105728",
+    );
+}
+
+#[test]
+fn test_large_felt_to_json_string() {
+    // Felts are always written unsigned, even just below the prime
+    assert!(
+        JsonValue::Number(-1)
+            .to_json_string() == "3618502788666131213697322783095070105623107215331596699973092056135872020480",
+    );
+    assert!(
+        JsonValue::Number(0x100000000000000000000000000000000)
+            .to_json_string() == "340282366920938463463374607431768211456",
+    );
+}
+
+#[test]
+fn test_get_any_object_field() {
+    let value = parse_json("{\"b\": 2, \"c\": 3}").unwrap();
+    assert!(value.get_any_object_field(array!["a", "b", "c"].span()) == Result::Ok(JsonValue::Number(2)));
+    assert!(value.get_any_object_field(array!["a"].span()) == Result::Err(JsonError::MissingField));
+}
@@ -1,5 +1,7 @@
 use alexandria_json::json::serialize_json;
 use alexandria_math::decimal::{Decimal, DecimalTrait};
+use alexandria_math::i257::{I257Impl, i257};
+use starknet::ContractAddress;
 
 // Test struct with all supported types
 #[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
@@ -55,6 +57,61 @@ struct Product {
     quantity: felt252,
 }
 
+// Test struct with nested struct fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
+struct Order {
+    id: felt252,
+    owner: Profile,
+    settings: Settings,
+}
+
+// Test struct with integer and Starknet fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq)]
+struct Transfer {
+    nonce: u64,
+    fee: u8,
+    delta: i32,
+    index: usize,
+    recipient: ContractAddress,
+    pnl: i257,
+}
+
+// Test struct with optional fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq)]
+struct Listing {
+    title: ByteArray,
+    price: Option<u64>,
+    #[json(skip_if_none)]
+    note: Option<ByteArray>,
+    seller: Option<Settings>,
+}
+
+// Test struct with array and span fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
+struct Portfolio {
+    ids: Array<u64>,
+    hashes: Span<felt252>,
+    prices: Array<Decimal>,
+    holders: Array<Price>,
+    matrix: Array<Array<u8>>,
+}
+
+// Test struct with renamed, skipped, defaulted and aliased fields
+#[derive(JsonSerialize, JsonDeserialize, Drop, Clone, PartialEq, Debug)]
+#[json(rename_all = "camelCase")]
+struct ApiUser {
+    user_id: u64,
+    display_name: ByteArray,
+    #[json(rename = "type")]
+    kind: ByteArray,
+    #[json(skip)]
+    cache: felt252,
+    #[json(default)]
+    login_count: u32,
+    #[json(alias = "mail", alias = "email_address")]
+    email: ByteArray,
+}
+
 #[test]
 fn test_serialize_mixed_types() {
     let mut user_tags = array![];
@@ -310,3 +367,116 @@ fn test_serialize_decimal_precision() {
         "{\"name\": \"Precise Item\", \"price\": 99.5, \"discount\": 5.25, \"in_stock\": true, \"quantity\": 10}";
     assert!(json_string == expected_json);
 }
+
+#[test]
+fn test_serialize_nested_structs() {
+    let order = Order {
+        id: 7,
+        owner: Profile {
+            id: "u1", name: "Bob", email: "bob@example.com", description: "", location: "Paris",
+        },
+        settings: Settings {
+            notifications_enabled: true, dark_mode: false, auto_save: true, is_premium: false,
+        },
+    };
+
+    let json_string = serialize_json(@order);
+
+    let expected_json =
+        "{\"id\": 7, \"owner\": {\"id\": \"u1\", \"name\": \"Bob\", \"email\": \"bob@example.com\", \"description\": \"\", \"location\": \"Paris\"}, \"settings\": {\"notifications_enabled\": true, \"dark_mode\": false, \"auto_save\": true, \"is_premium\": false}}";
+    assert!(json_string == expected_json);
+}
+
+#[test]
+fn test_serialize_integer_fields() {
+    let transfer = Transfer {
+        nonce: 18446744073709551615,
+        fee: 255,
+        delta: -42,
+        index: 3,
+        recipient: 0x1234.try_into().unwrap(),
+        pnl: I257Impl::new(99, true),
+    };
+
+    let json_string = serialize_json(@transfer);
+
+    let expected_json =
+        "{\"nonce\": 18446744073709551615, \"fee\": 255, \"delta\": -42, \"index\": 3, \"recipient\": 4660, \"pnl\": -99}";
+    assert!(json_string == expected_json);
+}
+
+#[test]
+fn test_serialize_large_felt_fields() {
+    // Felts just below the prime, e.g. hashes, are written unsigned and not as negative numbers
+    let account = Account {
+        user_id: -1,
+        balance: 0,
+        credit_score: -0x2a,
+        transaction_count: 0x100000000000000000000000000000000,
+    };
+
+    let json_string = serialize_json(@account);
+
+    let expected_json =
+        "{\"user_id\": 3618502788666131213697322783095070105623107215331596699973092056135872020480, \"balance\": 0, \"credit_score\": 3618502788666131213697322783095070105623107215331596699973092056135872020439, \"transaction_count\": 340282366920938463463374607431768211456}";
+    assert!(json_string == expected_json);
+}
+
+#[test]
+fn test_serialize_option_fields() {
+    let listing = Listing {
+        title: "Chair",
+        price: Option::Some(25),
+        note: Option::Some("used"),
+        seller: Option::Some(
+            Settings {
+                notifications_enabled: true, dark_mode: true, auto_save: false, is_premium: false,
+            },
+        ),
+    };
+    let expected_json =
+        "{\"title\": \"Chair\", \"price\": 25, \"note\": \"used\", \"seller\": {\"notifications_enabled\": true, \"dark_mode\": true, \"auto_save\": false, \"is_premium\": false}}";
+    assert!(serialize_json(@listing) == expected_json);
+
+    // `None` is `null`, or no key at all with `skip_if_none`
+    let listing = Listing {
+        title: "Table", price: Option::None, note: Option::None, seller: Option::None,
+    };
+    let expected_json = "{\"title\": \"Table\", \"price\": null, \"seller\": null}";
+    assert!(serialize_json(@listing) == expected_json);
+}
+
+#[test]
+fn test_serialize_array_fields() {
+    let portfolio = Portfolio {
+        ids: array![1, 2],
+        hashes: array![0x10].span(),
+        prices: array![DecimalTrait::from_int(5)],
+        holders: array![Price { amount: DecimalTrait::from_int(1), currency: "USD" }],
+        matrix: array![array![1, 2], array![], array![3]],
+    };
+
+    let json_string = serialize_json(@portfolio);
+
+    let expected_json =
+        "{\"ids\": [1, 2], \"hashes\": [16], \"prices\": [5.0], \"holders\": [{\"amount\": 1.0, \"currency\": \"USD\"}], \"matrix\": [[1, 2], [], [3]]}";
+    assert!(json_string == expected_json);
+}
+
+#[test]
+fn test_serialize_renamed_fields() {
+    let user = ApiUser {
+        user_id: 1,
+        display_name: "Alice",
+        kind: "admin",
+        cache: 42,
+        login_count: 3,
+        email: "alice@example.com",
+    };
+
+    let json_string = serialize_json(@user);
+
+    let expected_json =
+        "{\"userId\": 1, \"displayName\": \"Alice\", \"type\": \"admin\", \"loginCount\": 3, \"email\": \"alice@example.com\"}";
+    assert!(json_string == expected_json);
+}
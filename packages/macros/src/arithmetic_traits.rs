@@ -0,0 +1,274 @@
+use cairo_lang_macro::{derive_macro, ProcMacroResult, TokenStream};
+
+use crate::codegen::{spanned, CodeBuilder};
+use crate::parse::{expand_struct_derive, FieldInfo, ItemInfo};
+
+/// Flavour of the `core::num::traits` arithmetic trait to derive.
+#[derive(Clone, Copy)]
+enum Flavour {
+    /// `Option` of the result, `None` as soon as a member overflows.
+    Checked,
+    /// Result wrapped around the bounds of every member.
+    Wrapping,
+    /// Result clamped to the bounds of every member.
+    Saturating,
+    /// Wrapped result and whether any member overflowed.
+    Overflowing,
+}
+
+impl Flavour {
+    fn prefix(self) -> &'static str {
+        match self {
+            Flavour::Checked => "Checked",
+            Flavour::Wrapping => "Wrapping",
+            Flavour::Saturating => "Saturating",
+            Flavour::Overflowing => "Overflowing",
+        }
+    }
+}
+
+struct ArithmeticInfo {
+    flavour: Flavour,
+    /// Name of the operation, e.g. `Add`.
+    op_name: String,
+}
+
+impl ArithmeticInfo {
+    fn new(flavour: Flavour, op_name: &str) -> Self {
+        Self {
+            flavour,
+            op_name: op_name.to_string(),
+        }
+    }
+
+    /// Name of the derived trait, e.g. `CheckedAdd`.
+    fn trait_name(&self) -> String {
+        format!("{}{}", self.flavour.prefix(), self.op_name)
+    }
+
+    /// Name of the trait function, e.g. `checked_add`.
+    fn fn_name(&self) -> String {
+        format!(
+            "{}_{}",
+            self.flavour.prefix().to_lowercase(),
+            self.op_name.to_lowercase()
+        )
+    }
+}
+
+fn generate_arithmetic_trait_impl(
+    info: &ArithmeticInfo,
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+) -> CodeBuilder {
+    let generic_params = s.generic_args();
+    let trait_name = info.trait_name();
+    let fn_name = info.fn_name();
+    let trait_path = format!("core::num::traits::{trait_name}");
+
+    let bounds = s
+        .generic_field_types(fields)
+        .iter()
+        .flat_map(|ty| {
+            vec![
+                format!("+{}<{}>", trait_path, ty),
+                format!("+core::traits::Drop<{}>", ty),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let trait_bounds = s.impl_generic_params(&bounds);
+
+    let return_type = match info.flavour {
+        Flavour::Checked => format!("Option<{}{}>", s.name, generic_params),
+        Flavour::Wrapping | Flavour::Saturating => format!("{}{}", s.name, generic_params),
+        Flavour::Overflowing => format!("({}{}, bool)", s.name, generic_params),
+    };
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+impl {0}{1}{2}
+of {3}<{0}{4}> {{
+    fn {5}(self: {0}{4}, v: {0}{4}) -> {6} {{
+        ",
+        s.name, trait_name, trait_bounds, trait_path, generic_params, fn_name, return_type
+    ));
+
+    match info.flavour {
+        Flavour::Checked => {
+            // Every member is computed before building the result, so that `?` returns
+            // `None` on the first overflowing member.
+            let members_op = fields.iter().map(|field| {
+                spanned(
+                    format!(
+                        "let result_{0} = {1}::{2}(self.{0}, v.{0})?;",
+                        field.name, trait_path, fn_name
+                    ),
+                    &field.span,
+                )
+            });
+            code.push_joined(members_op, "\n        ");
+            code.push(format!("\n        Option::Some({} {{ ", s.name));
+            code.push(result_members(fields));
+            code.push(" })");
+        }
+        Flavour::Wrapping | Flavour::Saturating => {
+            let members_op = fields.iter().map(|field| {
+                spanned(
+                    format!(
+                        "{0}: {1}::{2}(self.{0}, v.{0})",
+                        field.name, trait_path, fn_name
+                    ),
+                    &field.span,
+                )
+            });
+            code.push(format!("{} {{ ", s.name));
+            code.push_joined(members_op, ", ");
+            code.push(" }");
+        }
+        Flavour::Overflowing => {
+            let members_op = fields.iter().map(|field| {
+                spanned(
+                    format!(
+                        "let (result_{0}, overflow_{0}) = {1}::{2}(self.{0}, v.{0});",
+                        field.name, trait_path, fn_name
+                    ),
+                    &field.span,
+                )
+            });
+            code.push_joined(members_op, "\n        ");
+            let overflow = if fields.is_empty() {
+                "false".to_string()
+            } else {
+                fields
+                    .iter()
+                    .map(|field| format!("overflow_{}", field.name))
+                    .collect::<Vec<_>>()
+                    .join(" || ")
+            };
+            code.push(format!("\n        ({} {{ ", s.name));
+            code.push(result_members(fields));
+            code.push(format!(" }}, {overflow})"));
+        }
+    }
+    code.push("\n    }\n}\n");
+    code
+}
+
+/// Members of a struct literal built from the `result_{name}` locals.
+fn result_members(fields: &[FieldInfo]) -> String {
+    fields
+        .iter()
+        .map(|field| format!("{0}: result_{0}", field.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn expand(token_stream: TokenStream, flavour: Flavour, op_name: &str) -> ProcMacroResult {
+    let info = ArithmeticInfo::new(flavour, op_name);
+    expand_struct_derive(token_stream, &info.trait_name(), |s, fields| {
+        Ok(generate_arithmetic_trait_impl(&info, s, fields))
+    })
+}
+
+/// Adds implementation for the `core::num::traits::CheckedAdd` trait.
+///
+/// Returns `None` as soon as the addition of a member overflows. All members of
+/// the struct must already implement the `CheckedAdd` trait.
+#[derive_macro]
+pub fn checked_add(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Checked, "Add")
+}
+
+/// Adds implementation for the `core::num::traits::CheckedSub` trait.
+///
+/// Returns `None` as soon as the subtraction of a member overflows. All members of
+/// the struct must already implement the `CheckedSub` trait.
+#[derive_macro]
+pub fn checked_sub(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Checked, "Sub")
+}
+
+/// Adds implementation for the `core::num::traits::CheckedMul` trait.
+///
+/// Returns `None` as soon as the multiplication of a member overflows. All members of
+/// the struct must already implement the `CheckedMul` trait.
+#[derive_macro]
+pub fn checked_mul(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Checked, "Mul")
+}
+
+/// Adds implementation for the `core::num::traits::WrappingAdd` trait.
+///
+/// All members of the struct must already implement the `WrappingAdd` trait.
+#[derive_macro]
+pub fn wrapping_add(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Wrapping, "Add")
+}
+
+/// Adds implementation for the `core::num::traits::WrappingSub` trait.
+///
+/// All members of the struct must already implement the `WrappingSub` trait.
+#[derive_macro]
+pub fn wrapping_sub(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Wrapping, "Sub")
+}
+
+/// Adds implementation for the `core::num::traits::WrappingMul` trait.
+///
+/// All members of the struct must already implement the `WrappingMul` trait.
+#[derive_macro]
+pub fn wrapping_mul(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Wrapping, "Mul")
+}
+
+/// Adds implementation for the `core::num::traits::SaturatingAdd` trait.
+///
+/// All members of the struct must already implement the `SaturatingAdd` trait.
+#[derive_macro]
+pub fn saturating_add(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Saturating, "Add")
+}
+
+/// Adds implementation for the `core::num::traits::SaturatingSub` trait.
+///
+/// All members of the struct must already implement the `SaturatingSub` trait.
+#[derive_macro]
+pub fn saturating_sub(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Saturating, "Sub")
+}
+
+/// Adds implementation for the `core::num::traits::SaturatingMul` trait.
+///
+/// All members of the struct must already implement the `SaturatingMul` trait.
+#[derive_macro]
+pub fn saturating_mul(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Saturating, "Mul")
+}
+
+/// Adds implementation for the `core::num::traits::OverflowingAdd` trait.
+///
+/// Returns the wrapped result and whether the addition of any member overflowed.
+/// All members of the struct must already implement the `OverflowingAdd` trait.
+#[derive_macro]
+pub fn overflowing_add(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Overflowing, "Add")
+}
+
+/// Adds implementation for the `core::num::traits::OverflowingSub` trait.
+///
+/// Returns the wrapped result and whether the subtraction of any member overflowed.
+/// All members of the struct must already implement the `OverflowingSub` trait.
+#[derive_macro]
+pub fn overflowing_sub(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Overflowing, "Sub")
+}
+
+/// Adds implementation for the `core::num::traits::OverflowingMul` trait.
+///
+/// Returns the wrapped result and whether the multiplication of any member overflowed.
+/// All members of the struct must already implement the `OverflowingMul` trait.
+#[derive_macro]
+pub fn overflowing_mul(token_stream: TokenStream) -> ProcMacroResult {
+    expand(token_stream, Flavour::Overflowing, "Mul")
+}
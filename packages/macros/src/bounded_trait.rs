@@ -0,0 +1,70 @@
+use crate::codegen::{spanned, CodeBuilder};
+use crate::parse::{expand_struct_derive, FieldInfo, ItemInfo};
+use cairo_lang_macro::{derive_macro, ProcMacroResult, TokenStream};
+
+fn generate_bounded_trait_impl(s: &ItemInfo, fields: &[FieldInfo]) -> CodeBuilder {
+    let generic_params = s.generic_args();
+
+    let bounds = s
+        .generic_field_types(fields)
+        .iter()
+        .flat_map(|ty| {
+            vec![
+                format!("+core::num::traits::Bounded<{}>", ty),
+                format!("+core::traits::Drop<{}>", ty),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let trait_bounds = s.impl_generic_params(&bounds);
+
+    let bound_const = |name: &'static str| {
+        fields.iter().map(move |field| {
+            spanned(
+                format!(
+                    "{}: core::num::traits::Bounded::<{}>::{}",
+                    field.name, field.field_type, name
+                ),
+                &field.span,
+            )
+        })
+    };
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+impl {0}BoundedImpl{1}
+of core::num::traits::Bounded<{0}{2}> {{
+    const MIN: {0}{2} = {0} {{ ",
+        s.name, trait_bounds, generic_params
+    ));
+    code.push_joined(bound_const("MIN"), ", ");
+    code.push(format!(
+        " }};\n    const MAX: {}{} = {} {{ ",
+        s.name, generic_params, s.name
+    ));
+    code.push_joined(bound_const("MAX"), ", ");
+    code.push(" };\n}\n");
+    code
+}
+
+/// Adds implementation of the `core::num::traits::Bounded` trait.
+///
+/// The bounds of the struct are made of the bounds of its members, so all members
+/// of the struct must already implement the `Bounded` trait.
+///
+/// ```
+/// #[derive(Bounded, PartialEq, Debug)]
+/// struct Point {
+///     x: u64,
+///     y: u8,
+/// }
+///
+/// assert_eq!(Bounded::MIN, Point { x: 0, y: 0 });
+/// assert_eq!(Bounded::MAX, Point { x: 0xffffffffffffffff, y: 0xff });
+/// ```
+#[derive_macro]
+pub fn bounded(token_stream: TokenStream) -> ProcMacroResult {
+    expand_struct_derive(token_stream, "Bounded", |s, fields| {
+        Ok(generate_bounded_trait_impl(s, fields))
+    })
+}
@@ -0,0 +1,57 @@
+use cairo_lang_macro::{TextSpan, Token, TokenStream, TokenTree};
+
+/// Generated Cairo code, built piece by piece so that the code generated for a part of the
+/// user's item (e.g. a field) keeps pointing at it. Compiler diagnostics in the expansion are
+/// then reported on that part instead of on the whole macro call.
+#[derive(Default)]
+pub(crate) struct CodeBuilder {
+    tokens: Vec<TokenTree>,
+}
+
+impl CodeBuilder {
+    /// Appends code attributed to the macro call site.
+    pub(crate) fn push(&mut self, code: impl AsRef<str>) {
+        self.push_spanned(code, &TextSpan::call_site());
+    }
+
+    /// Appends code originating from `span` in the user's code.
+    pub(crate) fn push_spanned(&mut self, code: impl AsRef<str>, span: &TextSpan) {
+        self.tokens
+            .push(TokenTree::Ident(Token::new(code, span.clone())));
+    }
+
+    /// Appends tokens of the user's code, keeping their original spans.
+    pub(crate) fn push_tokens(&mut self, token_stream: TokenStream) {
+        self.tokens.extend(token_stream.tokens);
+    }
+
+    /// Appends the code of `part`.
+    pub(crate) fn append(&mut self, part: CodeBuilder) {
+        self.tokens.extend(part.tokens);
+    }
+
+    /// Appends the code of `parts` separated by `separator`.
+    pub(crate) fn push_joined(
+        &mut self,
+        parts: impl IntoIterator<Item = CodeBuilder>,
+        separator: &str,
+    ) {
+        for (i, part) in parts.into_iter().enumerate() {
+            if i > 0 {
+                self.push(separator);
+            }
+            self.tokens.extend(part.tokens);
+        }
+    }
+
+    pub(crate) fn build(self) -> TokenStream {
+        TokenStream::new(self.tokens)
+    }
+}
+
+/// Code originating from `span` in the user's code.
+pub(crate) fn spanned(code: impl AsRef<str>, span: &TextSpan) -> CodeBuilder {
+    let mut builder = CodeBuilder::default();
+    builder.push_spanned(code, span);
+    builder
+}
@@ -0,0 +1,496 @@
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::num_traits::{pow, One, Signed, ToPrimitive, Zero};
+use cairo_lang_macro::{inline_macro, Diagnostic, ProcMacroResult, TextSpan, TokenStream};
+
+use crate::codegen::spanned;
+use crate::int_types::IntType;
+use crate::parse::error_result;
+use crate::token_tree_parser::{parse_integer_literal, token_stream_span, TokenTreeParser};
+
+/// Largest number of bits an intermediate result may have, so that a typo such as
+/// `2 ** 2 ** 100` fails instead of exhausting the memory of the compiler.
+const MAX_BITS: u64 = 1 << 16;
+
+/// Binary operators from the lowest to the highest precedence, `**` binds tighter than
+/// all of them and than the unary `-`.
+const BINARY_OPERATORS: [&[&str]; 6] = [
+    &["|"],
+    &["^"],
+    &["&"],
+    &["<<", ">>"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+/// Operators recognized by the tokenizer, longest first.
+const OPERATORS: [&str; 11] = ["**", "<<", ">>", "+", "-", "*", "/", "%", "&", "|", "^"];
+
+/// An error in an expression, with the byte offsets of the faulty part of the expression.
+#[derive(Debug)]
+pub(crate) struct EvalError {
+    pub(crate) message: String,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl EvalError {
+    fn new(message: impl ToString, start: usize, end: usize) -> Self {
+        Self {
+            message: message.to_string(),
+            start,
+            end,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum TokenKind {
+    Int(BigInt),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token>, EvalError> {
+    let bytes = expr.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while let Some(c) = expr[pos..].chars().next() {
+        if c.is_whitespace() {
+            pos += c.len_utf8();
+            continue;
+        }
+
+        let start = pos;
+        let kind = if c.is_ascii_alphanumeric() || c == '_' {
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                pos += 1;
+            }
+            let word = &expr[start..pos];
+            if c.is_ascii_digit() {
+                let value = parse_integer_literal(word).ok_or_else(|| {
+                    EvalError::new(format!("Invalid integer literal `{word}`"), start, pos)
+                })?;
+                TokenKind::Int(value)
+            } else {
+                TokenKind::Ident(word.to_string())
+            }
+        } else if let Some(op) = OPERATORS.iter().find(|op| expr[pos..].starts_with(**op)) {
+            pos += op.len();
+            TokenKind::Op(op)
+        } else {
+            pos += c.len_utf8();
+            match c {
+                '(' => TokenKind::LParen,
+                ')' => TokenKind::RParen,
+                ',' => TokenKind::Comma,
+                _ => return Err(EvalError::new(format!("Unexpected `{c}`"), start, pos)),
+            }
+        };
+        tokens.push(Token {
+            kind,
+            start,
+            end: pos,
+        });
+    }
+    Ok(tokens)
+}
+
+/// Evaluates an integer expression with arbitrary precision.
+///
+/// Supports the binary operators `+ - * / % ** << >> & | ^` with Rust precedence (`**` binding
+/// tightest), the unary `-`, parentheses and the functions `min`, `max`, `gcd`,
+/// `inv_mod(a, m)` and `pow_mod(base, exp, m)`. Division truncates toward zero.
+pub(crate) fn evaluate(expr: &str) -> Result<BigInt, EvalError> {
+    let tokens = tokenize(expr)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        end: expr.len(),
+    };
+    let value = parser.parse_binary(0)?;
+    match parser.peek() {
+        None => Ok(value),
+        Some(token) => Err(EvalError::new(
+            "Expected an operator",
+            token.start,
+            token.end,
+        )),
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    /// Length of the expression, where errors about a missing token point.
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn peek_op(&self, ops: &[&str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token {
+                kind: TokenKind::Op(op),
+                ..
+            }) if ops.contains(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    fn expect(&mut self, kind: TokenKind, description: &str) -> Result<(), EvalError> {
+        let end = self.end;
+        match self.next() {
+            Some(token) if token.kind == kind => Ok(()),
+            Some(token) => Err(EvalError::new(
+                format!("Expected {description}"),
+                token.start,
+                token.end,
+            )),
+            None => Err(EvalError::new(format!("Expected {description}"), end, end)),
+        }
+    }
+
+    fn parse_binary(&mut self, level: usize) -> Result<BigInt, EvalError> {
+        if level == BINARY_OPERATORS.len() {
+            return self.parse_unary();
+        }
+
+        let mut lhs = self.parse_binary(level + 1)?;
+        while let Some(op) = self.peek_op(BINARY_OPERATORS[level]) {
+            let token = self.next().expect("peeked an operator");
+            let (start, end) = (token.start, token.end);
+            let rhs = self.parse_binary(level + 1)?;
+            lhs = apply_binary(op, lhs, rhs)
+                .map_err(|message| EvalError::new(message, start, end))?;
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<BigInt, EvalError> {
+        if self.peek_op(&["-"]).is_some() {
+            self.next();
+            return Ok(-self.parse_unary()?);
+        }
+        self.parse_power()
+    }
+
+    fn parse_power(&mut self) -> Result<BigInt, EvalError> {
+        let base = self.parse_primary()?;
+        if self.peek_op(&["**"]).is_none() {
+            return Ok(base);
+        }
+        let token = self.next().expect("peeked an operator");
+        let (start, end) = (token.start, token.end);
+        // Right associative: `2 ** 3 ** 2` is `2 ** 9`.
+        let exp = self.parse_unary()?;
+        apply_binary("**", base, exp).map_err(|message| EvalError::new(message, start, end))
+    }
+
+    fn parse_primary(&mut self) -> Result<BigInt, EvalError> {
+        let end = self.end;
+        let Some(token) = self.next() else {
+            return Err(EvalError::new("Expected a value", end, end));
+        };
+        let (start, token_end) = (token.start, token.end);
+        match &token.kind {
+            TokenKind::Int(value) => Ok(value.clone()),
+            TokenKind::LParen => {
+                let value = self.parse_binary(0)?;
+                self.expect(TokenKind::RParen, "`)`")?;
+                Ok(value)
+            }
+            TokenKind::Ident(name) => {
+                let name = name.clone();
+                self.expect(TokenKind::LParen, "`(` after the function name")?;
+                let mut args = vec![self.parse_binary(0)?];
+                while self
+                    .peek()
+                    .is_some_and(|token| token.kind == TokenKind::Comma)
+                {
+                    self.next();
+                    args.push(self.parse_binary(0)?);
+                }
+                self.expect(TokenKind::RParen, "`)`")?;
+                call(&name, args).map_err(|message| EvalError::new(message, start, token_end))
+            }
+            _ => Err(EvalError::new("Expected a value", start, token_end)),
+        }
+    }
+}
+
+fn check_size(value: BigInt) -> Result<BigInt, String> {
+    if value.bits() > MAX_BITS {
+        return Err(format!("Intermediate result exceeds {MAX_BITS} bits"));
+    }
+    Ok(value)
+}
+
+fn apply_binary(op: &str, lhs: BigInt, rhs: BigInt) -> Result<BigInt, String> {
+    let result = match op {
+        "+" => lhs + rhs,
+        "-" => lhs - rhs,
+        "*" => lhs * rhs,
+        "/" | "%" if rhs.is_zero() => return Err("Division by zero".to_string()),
+        "/" => lhs / rhs,
+        "%" => lhs % rhs,
+        "&" => lhs & rhs,
+        "|" => lhs | rhs,
+        "^" => lhs ^ rhs,
+        "<<" | ">>" | "**" => {
+            let Some(amount) = rhs.to_u64().filter(|amount| *amount <= MAX_BITS) else {
+                return Err(format!(
+                    "Invalid right operand {rhs} of `{op}`, expected an integer between 0 and {MAX_BITS}"
+                ));
+            };
+            match op {
+                "<<" => lhs << amount,
+                ">>" => lhs >> amount,
+                // 0, 1 and -1 stay small whatever the exponent.
+                _ if lhs.bits() <= 1 || lhs.bits() * amount <= MAX_BITS => {
+                    pow(lhs, amount as usize)
+                }
+                _ => return Err(format!("Intermediate result exceeds {MAX_BITS} bits")),
+            }
+        }
+        _ => unreachable!("unknown operator `{op}`"),
+    };
+    check_size(result)
+}
+
+fn call(name: &str, args: Vec<BigInt>) -> Result<BigInt, String> {
+    let expect_args = |count: usize| {
+        if args.len() == count {
+            Ok(())
+        } else {
+            Err(format!(
+                "`{name}` takes {count} arguments but {} were given",
+                args.len()
+            ))
+        }
+    };
+
+    match name {
+        "min" => Ok(args.into_iter().min().expect("at least one argument")),
+        "max" => Ok(args.into_iter().max().expect("at least one argument")),
+        "gcd" => {
+            expect_args(2)?;
+            Ok(gcd(&args[0], &args[1]))
+        }
+        "inv_mod" => {
+            expect_args(2)?;
+            let (value, modulus) = (&args[0], &args[1]);
+            if !modulus.is_positive() {
+                return Err("The modulus of `inv_mod` must be positive".to_string());
+            }
+            inv_mod(value, modulus)
+                .ok_or_else(|| format!("{value} has no inverse modulo {modulus}"))
+        }
+        "pow_mod" => {
+            expect_args(3)?;
+            let (base, exp, modulus) = (&args[0], &args[1], &args[2]);
+            if exp.is_negative() {
+                return Err("The exponent of `pow_mod` must be non-negative".to_string());
+            }
+            if !modulus.is_positive() {
+                return Err("The modulus of `pow_mod` must be positive".to_string());
+            }
+            Ok(base.mod_floor_positive(modulus).modpow(exp, modulus))
+        }
+        _ => Err(format!(
+            "Unknown function `{name}`, expected one of min, max, gcd, inv_mod, pow_mod"
+        )),
+    }
+}
+
+fn gcd(a: &BigInt, b: &BigInt) -> BigInt {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        let rem = &a % &b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Inverse of `value` modulo `modulus`, in `[0, modulus)`.
+fn inv_mod(value: &BigInt, modulus: &BigInt) -> Option<BigInt> {
+    // Extended Euclid, keeping only the coefficient of `value`.
+    let (mut old_r, mut r) = (value.mod_floor_positive(modulus), modulus.clone());
+    let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+    while !r.is_zero() {
+        let quotient = &old_r / &r;
+        (old_r, r) = (r.clone(), old_r - &quotient * &r);
+        (old_s, s) = (s.clone(), old_s - &quotient * &s);
+    }
+    if !old_r.is_one() {
+        return None;
+    }
+    Some(old_s.mod_floor_positive(modulus))
+}
+
+trait ModFloorPositive {
+    /// Remainder of the division by a positive `modulus`, in `[0, modulus)`.
+    fn mod_floor_positive(&self, modulus: &BigInt) -> BigInt;
+}
+
+impl ModFloorPositive for BigInt {
+    fn mod_floor_positive(&self, modulus: &BigInt) -> BigInt {
+        let rem = self % modulus;
+        if rem.sign() == Sign::Minus {
+            rem + modulus
+        } else {
+            rem
+        }
+    }
+}
+
+/// Compile-time integer expression evaluation.
+///
+/// Takes an integer expression and an optional integer type, evaluates the expression with
+/// arbitrary precision and emits the result as a literal. With a type, the result is checked to
+/// fit in it and emitted as a typed literal.
+///
+/// ```
+/// const P: u256 = const_eval!(2 ** 255 - 19, u256);
+/// const D: u256 = const_eval!((2 ** 255 - 19 - 121665) * inv_mod(121666, 2 ** 255 - 19) % (2 ** 255 - 19), u256);
+/// ```
+#[inline_macro]
+pub fn const_eval(token_stream: TokenStream) -> ProcMacroResult {
+    let macro_args = match TokenTreeParser::parse_inline_macro_args("const_eval", &token_stream) {
+        Ok(args) => args,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
+
+    if !(1..=2).contains(&macro_args.len()) {
+        return error_result(
+            Diagnostic::span_error(
+                token_stream_span(&token_stream),
+                format!(
+                    "Invalid number of arguments, expected 1 or 2 but got {}",
+                    macro_args.len()
+                ),
+            )
+            .into(),
+        );
+    }
+
+    if let Some(arg) = macro_args.iter().find(|arg| arg.name.is_some()) {
+        return error_result(
+            arg.error("`const_eval!` doesn't take named arguments")
+                .into(),
+        );
+    }
+
+    let expr = &macro_args[0];
+    let value = match evaluate(&expr.value) {
+        Ok(value) => value,
+        Err(err) => {
+            let span = TextSpan::new(
+                expr.span.start + err.start as u32,
+                expr.span.start + err.end as u32,
+            );
+            return error_result(Diagnostic::span_error(span, err.message).into());
+        }
+    };
+
+    let literal = match macro_args.get(1) {
+        Some(arg) => {
+            let ty = match IntType::from_arg(arg) {
+                Ok(ty) => ty,
+                Err(diagnostic) => return error_result(diagnostic.into()),
+            };
+            if !ty.contains(&value) {
+                return error_result(expr.error(ty.out_of_range_message(&value)).into());
+            }
+            ty.literal(&value)
+        }
+        None => value.to_string(),
+    };
+
+    ProcMacroResult::new(spanned(literal, &token_stream_span(&token_stream)).build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(expr: &str) -> String {
+        evaluate(expr).unwrap().to_string()
+    }
+
+    fn eval_err(expr: &str) -> (String, usize, usize) {
+        let err = evaluate(expr).unwrap_err();
+        (err.message, err.start, err.end)
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(eval("1 + 2 * 3"), "7");
+        assert_eq!(eval("(1 + 2) * 3"), "9");
+        assert_eq!(eval("2 ** 3 ** 2"), "512");
+        assert_eq!(eval("-2 ** 2"), "-4");
+        assert_eq!(eval("1 << 4 + 1"), "32");
+        assert_eq!(eval("6 & 3 | 8 ^ 1"), "11");
+        assert_eq!(eval("7 - 2 - 1"), "4");
+    }
+
+    #[test]
+    fn test_operators() {
+        assert_eq!(eval("-7 / 2"), "-3");
+        assert_eq!(eval("-7 % 2"), "-1");
+        assert_eq!(eval("0xff >> 4"), "15");
+        assert_eq!(eval("1_000 * 0b10"), "2000");
+        assert_eq!(
+            eval("2 ** 255 - 19"),
+            "57896044618658097711785492504343953926634992332820282019728792003956564819949"
+        );
+    }
+
+    #[test]
+    fn test_functions() {
+        assert_eq!(eval("min(3, -1, 2)"), "-1");
+        assert_eq!(eval("max(3, -1, 2)"), "3");
+        assert_eq!(eval("gcd(-12, 18)"), "6");
+        assert_eq!(eval("inv_mod(3, 7)"), "5");
+        assert_eq!(eval("inv_mod(-3, 7)"), "2");
+        assert_eq!(eval("pow_mod(3, 200, 7)"), "2");
+        assert_eq!(eval("pow_mod(-2, 3, 7)"), "6");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(eval_err("1 / 0"), ("Division by zero".to_string(), 2, 3));
+        assert_eq!(eval_err("1 +"), ("Expected a value".to_string(), 3, 3));
+        assert_eq!(eval_err("(1 + 2"), ("Expected `)`".to_string(), 6, 6));
+        assert_eq!(eval_err("1 2"), ("Expected an operator".to_string(), 2, 3));
+        assert_eq!(eval_err("1 $ 2"), ("Unexpected `$`".to_string(), 2, 3));
+        assert_eq!(
+            eval_err("inv_mod(2, 4)"),
+            ("2 has no inverse modulo 4".to_string(), 0, 7)
+        );
+        assert_eq!(
+            eval_err("foo(1)").0,
+            "Unknown function `foo`, expected one of min, max, gcd, inv_mod, pow_mod"
+        );
+        assert_eq!(eval_err("2 ** 2 ** 100").1, 2);
+        assert_eq!(eval_err("0xg").0, "Invalid integer literal `0xg`");
+    }
+}
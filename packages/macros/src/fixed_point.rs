@@ -0,0 +1,175 @@
+use std::str::FromStr;
+
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{pow, Signed, ToPrimitive, Zero};
+use bigdecimal::BigDecimal;
+use cairo_lang_macro::{inline_macro, Diagnostic, ProcMacroResult, TokenStream};
+
+use crate::codegen::spanned;
+use crate::int_types::IntType;
+use crate::parse::error_result;
+use crate::token_tree_parser::{token_stream_span, MacroArg, TokenTreeParser};
+
+/// Number of decimal places of `alexandria_math::decimal::Decimal`.
+const DECIMAL_PLACES: usize = 18;
+
+/// Number of decimal places of a wad, see `alexandria_math::wad_ray_math`.
+const WAD_PLACES: usize = 18;
+
+/// Number of decimal places of a ray, see `alexandria_math::wad_ray_math`.
+const RAY_PLACES: usize = 27;
+
+/// Largest power of ten a literal is scaled by, beyond it the value is out of range of any type.
+const MAX_EXPONENT: i64 = 1024;
+
+/// Value of a decimal literal argument, e.g. `-3.035`, `1e-9` or `"1_000.5"`, scaled by
+/// `10^places`.
+///
+/// Reports an error if the literal has more than `places` decimal places, as they would be lost.
+fn parse_scaled(arg: &MacroArg, places: usize) -> Result<BigInt, Diagnostic> {
+    let text = arg.as_string().unwrap_or_else(|| arg.value.clone());
+    let text = text
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .collect::<String>();
+    let value = BigDecimal::from_str(&text).map_err(|_| {
+        arg.error(format!(
+            "Invalid decimal literal `{}`, expected e.g. `-3.035`",
+            arg.value
+        ))
+    })?;
+
+    let (digits, scale) = value.into_bigint_and_exponent();
+    if digits.is_zero() {
+        return Ok(digits);
+    }
+    let shift = places as i64 - scale;
+    if shift > MAX_EXPONENT {
+        return Err(arg.error(format!("`{}` is out of range", arg.value)));
+    }
+    if shift >= 0 {
+        return Ok(digits * pow(BigInt::from(10), shift as usize));
+    }
+
+    let precision_error = || {
+        arg.error(format!(
+            "`{}` has more than {} decimal places, which would be lost",
+            arg.value, places
+        ))
+    };
+    if -shift > MAX_EXPONENT {
+        return Err(precision_error());
+    }
+    let divisor = pow(BigInt::from(10), (-shift) as usize);
+    if !(&digits % &divisor).is_zero() {
+        return Err(precision_error());
+    }
+    Ok(digits / divisor)
+}
+
+/// Compile-time `alexandria_math::decimal::Decimal` literal.
+///
+/// Takes a decimal literal, with at most 18 decimal places and an integer part fitting in
+/// `u64`, and emits the `Decimal` struct literal. `-0` is emitted as a positive zero.
+///
+/// ```
+/// let x: Decimal = dec!(-3.035);
+/// assert_eq!(x, DecimalTrait::from_raw_parts_signed(3, 35000000000000000, true));
+/// ```
+#[inline_macro]
+pub fn dec(token_stream: TokenStream) -> ProcMacroResult {
+    let arg = match TokenTreeParser::parse_single_inline_macro_arg("dec", &token_stream) {
+        Ok(arg) => arg,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
+    let value = match parse_scaled(&arg, DECIMAL_PLACES) {
+        Ok(value) => value,
+        Err(diagnostic) => return error_result(diagnostic.into()),
+    };
+
+    let scale = pow(BigInt::from(10), DECIMAL_PLACES);
+    let magnitude = value.abs();
+    let Some(int_part) = (&magnitude / &scale).to_u64() else {
+        return error_result(
+            arg.error(format!(
+                "`{}` is out of the range of `Decimal`, whose integer part is at most {}",
+                arg.value,
+                u64::MAX
+            ))
+            .into(),
+        );
+    };
+    let frac_part = &magnitude % &scale;
+
+    let literal = format!(
+        "alexandria_math::decimal::Decimal {{ int_part: {}_u64, frac_part: {}_u64, is_negative: {} }}",
+        int_part,
+        frac_part,
+        value.is_negative()
+    );
+    ProcMacroResult::new(spanned(literal, &token_stream_span(&token_stream)).build())
+}
+
+/// Expands the fixed-point macro `name`, emitting its single decimal literal argument scaled by
+/// `10^places` as a `u256` literal.
+fn expand_scaled_u256(name: &str, token_stream: TokenStream, places: usize) -> ProcMacroResult {
+    let arg = match TokenTreeParser::parse_single_inline_macro_arg(name, &token_stream) {
+        Ok(arg) => arg,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
+    let value = match parse_scaled(&arg, places) {
+        Ok(value) => value,
+        Err(diagnostic) => return error_result(diagnostic.into()),
+    };
+    if value.is_negative() {
+        return error_result(
+            arg.error(format!(
+                "`{}` is negative, `{}!` values are unsigned `u256`",
+                arg.value, name
+            ))
+            .into(),
+        );
+    }
+
+    let ty = IntType::parse("u256").unwrap();
+    if !ty.contains(&value) {
+        return error_result(
+            arg.error(format!(
+                "`{}!({})` = {}",
+                name,
+                arg.value,
+                ty.out_of_range_message(&value)
+            ))
+            .into(),
+        );
+    }
+    ProcMacroResult::new(spanned(ty.literal(&value), &token_stream_span(&token_stream)).build())
+}
+
+/// Compile-time wad, a `u256` with 18 decimal places as used by
+/// `alexandria_math::wad_ray_math`.
+///
+/// Takes a non-negative decimal literal with at most 18 decimal places.
+///
+/// ```
+/// const ONE_AND_A_HALF: u256 = wad!(1.5);
+/// assert_eq!(ONE_AND_A_HALF, 1_500_000_000_000_000_000);
+/// ```
+#[inline_macro]
+pub fn wad(token_stream: TokenStream) -> ProcMacroResult {
+    expand_scaled_u256("wad", token_stream, WAD_PLACES)
+}
+
+/// Compile-time ray, a `u256` with 27 decimal places as used by
+/// `alexandria_math::wad_ray_math`.
+///
+/// Takes a non-negative decimal literal with at most 27 decimal places.
+///
+/// ```
+/// const NANO: u256 = ray!(0.000000001);
+/// assert_eq!(NANO, 1_000_000_000_000_000_000);
+/// ```
+#[inline_macro]
+pub fn ray(token_stream: TokenStream) -> ProcMacroResult {
+    expand_scaled_u256("ray", token_stream, RAY_PLACES)
+}
@@ -1,120 +1,107 @@
-use cairo_lang_macro::{attribute_macro, Diagnostic, ProcMacroResult, TokenStream};
+use cairo_lang_macro::{
+    attribute_macro, Diagnostic, Diagnostics, ProcMacroResult, TextSpan, TokenStream,
+};
+
+use crate::codegen::{spanned, CodeBuilder};
+use crate::parse::{error_result, parse_item, ItemInfo};
 
 #[attribute_macro]
 pub fn generate_events(_attr: TokenStream, token_stream: TokenStream) -> ProcMacroResult {
-    let input = token_stream.to_string();
+    let item = match parse_item(&token_stream) {
+        Ok(item) => item,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
 
     // Validate that this is applied to a proper event enum
-    if let Err(validation_error) = validate_event_enum(&input) {
-        return *validation_error;
+    if let Err(validation_error) = validate_event_enum(&item) {
+        return error_result(validation_error);
     }
 
     // Extract struct names
-    let struct_names = extract_struct_names(&input);
+    let (struct_names, warnings) = extract_struct_names(&item);
 
     // Generate structs with derives
     let structs = generate_structs(&struct_names);
 
-    let cleaned_enum = clean_enum(&input);
-
-    let output = format!("{cleaned_enum}\n{structs}");
+    // Keep the enum as written, the `#[generate_events]` attribute itself is not part of the input
+    let mut code = CodeBuilder::default();
+    code.push_tokens(token_stream);
+    code.push("\n");
+    code.push_joined(structs, "\n\n");
 
-    ProcMacroResult::new(TokenStream::new(output))
+    ProcMacroResult::new(code.build()).with_diagnostics(Diagnostics::new(warnings))
 }
 
-fn validate_event_enum(input: &str) -> Result<(), Box<ProcMacroResult>> {
-    let lines: Vec<&str> = input.lines().collect();
-
+fn validate_event_enum(item: &ItemInfo) -> Result<(), Diagnostics> {
     // Check 1: Must have #[event] attribute
-    let has_event_attr = lines.iter().any(|line| line.trim().starts_with("#[event]"));
+    let has_event_attr = item.attributes.iter().any(|attr| attr.name == "event");
 
-    if !has_event_attr {
-        return Err(Box::new(error_result(
+    if item.enum_variants().is_none() || !has_event_attr {
+        return Err(error(
+            item,
             "#[generate_events] can only be used on enums with #[event]",
-        )));
+        ));
     }
 
     // Check 2: Must have #[derive(..., starknet::Event, ...)]
-    let has_starknet_event_derive = lines.iter().any(|line| {
-        let trimmed = line.trim();
-        trimmed.starts_with("#[derive(") && trimmed.contains("starknet::Event")
+    let has_starknet_event_derive = item.attributes.iter().any(|attr| {
+        attr.name == "derive"
+            && attr
+                .args
+                .iter()
+                .any(|derive| derive.value == "starknet::Event")
     });
 
     if !has_starknet_event_derive {
-        return Err(Box::new(error_result(
+        return Err(error(
+            item,
             "#[generate_events] can only be used on enums that derive starknet::Event",
-        )));
+        ));
     }
 
     // Check 3: Must be a pub enum
-    let has_pub_enum = lines.iter().any(|line| {
-        let trimmed = line.trim();
-        trimmed.starts_with("pub enum") || trimmed.contains("pub enum")
-    });
-
-    if !has_pub_enum {
-        return Err(Box::new(error_result(
+    if !item.is_pub {
+        return Err(error(
+            item,
             "#[generate_events] can only be used on public enums.",
-        )));
+        ));
     }
 
     Ok(())
 }
 
-fn extract_struct_names(input: &str) -> Vec<String> {
+/// Collects the payload type of every variant with the variant span, warning about variants
+/// without one.
+fn extract_struct_names(item: &ItemInfo) -> (Vec<(String, TextSpan)>, Vec<Diagnostic>) {
     let mut struct_names = Vec::new();
-    let mut inside_enum = false;
-
-    for line in input.lines() {
-        let trimmed = line.trim();
-
-        if trimmed.contains("pub enum") {
-            inside_enum = true;
-            continue;
-        }
-
-        if inside_enum && trimmed == "}" {
-            break;
-        }
-
-        if inside_enum && trimmed.contains(':') && !trimmed.is_empty() {
-            if let Some(colon_idx) = trimmed.find(':') {
-                let after_colon = &trimmed[colon_idx + 1..];
-                let struct_name = after_colon.trim().trim_end_matches(',').trim();
-
-                if !struct_name.is_empty() {
-                    struct_names.push(struct_name.to_string());
-                }
-            }
+    let mut warnings = Vec::new();
+
+    for variant in item.enum_variants().unwrap_or_default() {
+        match &variant.payload {
+            Some(payload) => struct_names.push((payload.clone(), variant.span.clone())),
+            None => warnings.push(Diagnostic::span_warning(
+                variant.span.clone(),
+                format!(
+                    "Variant `{}` has no payload, no event struct is generated for it",
+                    variant.name
+                ),
+            )),
         }
     }
 
-    struct_names
+    (struct_names, warnings)
 }
 
-fn generate_structs(struct_names: &[String]) -> String {
+fn generate_structs(struct_names: &[(String, TextSpan)]) -> Vec<CodeBuilder> {
     struct_names.iter()
-        .map(|name| {
-            format!(
+        .map(|(name, span)| {
+            spanned(format!(
                 "#[derive(Drop, starknet::Event)]\npub struct {name} {{\n    pub data: Span<felt252>,\n}}"
-            )
-        })
-        .collect::<Vec<_>>()
-        .join("\n\n")
-}
-
-fn clean_enum(input: &str) -> String {
-    input
-        .lines()
-        .filter(|line| {
-            let trimmed = line.trim();
-            // Only remove #[generate_events] lines, keep everything else including #[derive(...)]
-            !trimmed.starts_with("#[generate_events]")
+            ), span)
         })
-        .collect::<Vec<_>>()
-        .join("\n")
+        .collect()
 }
 
-fn error_result(msg: &str) -> ProcMacroResult {
-    ProcMacroResult::new(TokenStream::empty()).with_diagnostics(Diagnostic::error(msg).into())
+fn error(item: &ItemInfo, msg: &str) -> Diagnostics {
+    Diagnostic::span_error(item.span.clone(), msg).into()
 }
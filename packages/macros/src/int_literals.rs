@@ -0,0 +1,157 @@
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::num_traits::{One, Signed, Zero};
+use cairo_lang_macro::{inline_macro, Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+
+use crate::codegen::spanned;
+use crate::parse::error_result;
+use crate::token_tree_parser::{
+    parse_integer_literal, token_stream_span, MacroArg, TokenTreeParser,
+};
+
+/// An integer literal argument of any size, and whether it is written with a minus sign.
+struct BigIntLiteral {
+    value: BigInt,
+    is_negative: bool,
+}
+
+/// Parses an integer literal argument, in decimal or hex, either bare or quoted e.g.
+/// `0x1234` or `"115792089237316195423570985008687907853269984665640564039457584007913129639935"`.
+fn parse_big_int(arg: &MacroArg) -> Result<BigIntLiteral, Diagnostic> {
+    let text = arg.as_string().unwrap_or_else(|| arg.value.clone());
+    let value = parse_integer_literal(&text).ok_or_else(|| {
+        arg.error(format!(
+            "Invalid integer literal `{}`, expected a decimal or hex integer",
+            arg.value
+        ))
+    })?;
+    Ok(BigIntLiteral {
+        value,
+        is_negative: text.trim_start().starts_with('-'),
+    })
+}
+
+/// Splits a non-negative value into `count` 128-bit limbs, least significant first, or returns
+/// `None` if it doesn't fit in them.
+fn u128_limbs(value: &BigInt, count: usize) -> Option<Vec<String>> {
+    if value.sign() == Sign::Minus || value.bits() > 128 * count as u64 {
+        return None;
+    }
+    let mask = (BigInt::one() << 128) - 1;
+    Some(
+        (0..count)
+            .map(|i| format!("0x{:x}_u128", (value >> (128 * i)) & &mask))
+            .collect(),
+    )
+}
+
+/// Parses the single argument of an unsigned literal macro and splits it into `count` limbs.
+fn parse_unsigned_limbs(
+    name: &str,
+    token_stream: &TokenStream,
+    count: usize,
+) -> Result<Vec<String>, Diagnostics> {
+    let arg = TokenTreeParser::parse_single_inline_macro_arg(name, token_stream)?;
+    let literal = parse_big_int(&arg)?;
+    if literal.is_negative {
+        return Err(arg
+            .error(format!(
+                "`{}` is negative, `{}` is unsigned",
+                arg.value, name
+            ))
+            .into());
+    }
+    u128_limbs(&literal.value, count).ok_or_else(|| {
+        arg.error(format!(
+            "`{}` is out of the range of `{}`, which is at most 2^{} - 1",
+            arg.value,
+            name,
+            128 * count
+        ))
+        .into()
+    })
+}
+
+/// Compile-time `u256` literal of any size.
+///
+/// Takes a decimal or hex integer, possibly quoted, and emits the `u256` struct literal with
+/// its `low` and `high` members.
+///
+/// ```
+/// const MAX: u256 = u256!(
+///     "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+/// );
+/// ```
+#[inline_macro]
+pub fn u256(token_stream: TokenStream) -> ProcMacroResult {
+    let limbs = match parse_unsigned_limbs("u256", &token_stream, 2) {
+        Ok(limbs) => limbs,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
+    let literal = format!("u256 {{ low: {}, high: {} }}", limbs[0], limbs[1]);
+    ProcMacroResult::new(spanned(literal, &token_stream_span(&token_stream)).build())
+}
+
+/// Compile-time `core::integer::u512` literal.
+///
+/// Takes a decimal or hex integer, possibly quoted, and emits the `u512` struct literal with
+/// its four 128-bit limbs, `limb0` being the least significant.
+///
+/// ```
+/// let x: u512 = u512!(0x1_00000000000000000000000000000000_00000000000000000000000000000000);
+/// assert_eq!(x.limb2, 1);
+/// ```
+#[inline_macro]
+pub fn u512(token_stream: TokenStream) -> ProcMacroResult {
+    let limbs = match parse_unsigned_limbs("u512", &token_stream, 4) {
+        Ok(limbs) => limbs,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
+    let literal = format!(
+        "core::integer::u512 {{ limb0: {}, limb1: {}, limb2: {}, limb3: {} }}",
+        limbs[0], limbs[1], limbs[2], limbs[3]
+    );
+    ProcMacroResult::new(spanned(literal, &token_stream_span(&token_stream)).build())
+}
+
+/// Compile-time `alexandria_math::i257::i257` literal.
+///
+/// Takes a decimal or hex integer, possibly quoted, whose absolute value fits in `u256`, and
+/// emits the `i257` struct literal, which can be used in constants. Negative zero is rejected,
+/// as `i257` operations don't accept it.
+///
+/// ```
+/// const X: i257 = i257!(-0x1234);
+/// assert!(X == I257Impl::new(0x1234, true));
+/// ```
+#[inline_macro]
+pub fn i257(token_stream: TokenStream) -> ProcMacroResult {
+    let arg = match TokenTreeParser::parse_single_inline_macro_arg("i257", &token_stream) {
+        Ok(arg) => arg,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
+    let literal = match parse_big_int(&arg) {
+        Ok(literal) => literal,
+        Err(diagnostic) => return error_result(diagnostic.into()),
+    };
+    if literal.is_negative && literal.value.is_zero() {
+        return error_result(
+            arg.error("Negative zero is not a valid `i257`, use `0` instead")
+                .into(),
+        );
+    }
+    let Some(limbs) = u128_limbs(&literal.value.abs(), 2) else {
+        return error_result(
+            arg.error(format!(
+                "`{}` is out of the range of `i257`, whose absolute value is at most 2^256 - 1",
+                arg.value
+            ))
+            .into(),
+        );
+    };
+
+    let code = format!(
+        "alexandria_math::i257::i257 {{ abs: u256 {{ low: {}, high: {} }}, is_negative: {} }}",
+        limbs[0], limbs[1], literal.is_negative
+    );
+    ProcMacroResult::new(spanned(code, &token_stream_span(&token_stream)).build())
+}
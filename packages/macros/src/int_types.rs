@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use bigdecimal::num_bigint::BigInt;
+use cairo_lang_macro::Diagnostic;
+
+use crate::token_tree_parser::MacroArg;
+
+/// Prime of the field of `felt252` values.
+const FELT252_PRIME: &str =
+    "3618502788666131213697322783095070105623107215331596699973092056135872020481";
+
+/// An integer type of the core library that macros can emit typed literals of.
+pub(crate) struct IntType {
+    pub(crate) name: &'static str,
+    pub(crate) min: BigInt,
+    pub(crate) max: BigInt,
+}
+
+/// Names of the types [`IntType::parse`] accepts, for diagnostics.
+const SUPPORTED_INT_TYPES: &str =
+    "u8, u16, u32, u64, u128, u256, usize, i8, i16, i32, i64, i128, felt252";
+
+impl IntType {
+    /// The integer type named `name`, e.g. `u64` or `core::integer::u64`.
+    pub(crate) fn parse(name: &str) -> Option<IntType> {
+        let name = name.trim();
+        let name = name.rsplit("::").next().unwrap_or(name);
+        let (name, bits, signed): (&'static str, u32, bool) = match name {
+            "u8" => ("u8", 8, false),
+            "u16" => ("u16", 16, false),
+            "u32" => ("u32", 32, false),
+            "u64" => ("u64", 64, false),
+            "u128" => ("u128", 128, false),
+            "u256" => ("u256", 256, false),
+            "usize" => ("usize", 32, false),
+            "i8" => ("i8", 8, true),
+            "i16" => ("i16", 16, true),
+            "i32" => ("i32", 32, true),
+            "i64" => ("i64", 64, true),
+            "i128" => ("i128", 128, true),
+            "felt252" => {
+                // Negative literals are reduced modulo the prime.
+                let max: BigInt = BigInt::parse_bytes(FELT252_PRIME.as_bytes(), 10)? - 1;
+                return Some(IntType {
+                    name: "felt252",
+                    min: -max.clone(),
+                    max,
+                });
+            }
+            _ => return None,
+        };
+
+        let one = BigInt::from(1);
+        let (min, max) = if signed {
+            let half = &one << (bits - 1);
+            (-half.clone(), half - 1)
+        } else {
+            (BigInt::from(0), (&one << bits) - 1)
+        };
+        Some(IntType { name, min, max })
+    }
+
+    /// The integer type named by a macro argument, reporting an error on it if unsupported.
+    pub(crate) fn from_arg(arg: &MacroArg) -> Result<IntType, Diagnostic> {
+        IntType::parse(&arg.value).ok_or_else(|| {
+            arg.error(format!(
+                "Unsupported type `{}`, expected one of {}",
+                arg.value, SUPPORTED_INT_TYPES
+            ))
+        })
+    }
+
+    pub(crate) fn contains(&self, value: &BigInt) -> bool {
+        &self.min <= value && value <= &self.max
+    }
+
+    /// Error message for a `value` out of the range of the type.
+    pub(crate) fn out_of_range_message(&self, value: impl Display) -> String {
+        format!(
+            "{} doesn't fit in `{}`, expected a value between {} and {}",
+            value, self.name, self.min, self.max
+        )
+    }
+
+    /// Typed literal of `value`, e.g. `-5_i8`.
+    pub(crate) fn literal(&self, value: &BigInt) -> String {
+        format!("{}_{}", value, self.name)
+    }
+}
@@ -1,142 +1,525 @@
-use crate::parse::{parse_struct_info, FieldInfo, StructInfo};
-use cairo_lang_macro::{derive_macro, ProcMacroResult, TokenStream};
+use crate::codegen::{spanned, CodeBuilder};
+use crate::parse::{expand_struct_derive, find_attribute, FieldInfo, ItemInfo};
+use cairo_lang_macro::{
+    derive_macro, executable_attribute, Diagnostic, Diagnostics, ProcMacroResult, TokenStream,
+};
 
-fn generate_field_deserialization(field: &FieldInfo) -> String {
-    let field_name = &field.name;
-    let field_type = &field.field_type;
+// Declares the `#[json]` helper attribute so that the compiler accepts it on structs and members.
+executable_attribute!("json");
+
+const SUPPORTED_TYPES: &str =
+    "`felt252`, `bool`, an integer type, `ContractAddress`, `EthAddress`, `ClassHash`, `u256`, `Decimal`, `ByteArray`, `Array<T>`, `Span<T>`, `Option<T>` of these";
+
+/// Unsigned integer types stored in a `JsonValue::Number`, converted with a range check.
+const UNSIGNED_INT_TYPES: [&str; 6] = ["u8", "u16", "u32", "u64", "u128", "usize"];
+
+/// Signed integer types stored in a `JsonValue::SignedNumber`, converted with a range check.
+const SIGNED_INT_TYPES: [&str; 5] = ["i8", "i16", "i32", "i64", "i128"];
+
+/// Starknet types stored in a `JsonValue::Number`, converted with a range check.
+const STARKNET_TYPES: [&str; 3] = ["ContractAddress", "EthAddress", "ClassHash"];
 
-    match normalize_type(field_type).as_str() {
-        "felt252" => {
-            format!("let {field_name} = alexandria_json::json::JsonValueHelper::as_number(@alexandria_json::json::JsonValueHelper::get_object_field(@value, \"{field_name}\")?)?;")
+/// JSON representation a field type is (de)serialized with.
+enum JsonType {
+    Felt252,
+    /// Unsigned integer and Starknet types, converted from and to a number.
+    Int,
+    /// Signed integer types, converted from a number and to a signed number.
+    SignedInt,
+    Bool,
+    U256,
+    Decimal,
+    ByteArray,
+    /// `Array<T>`, as a JSON array of its elements.
+    Array(Box<JsonType>),
+    /// `Span<T>`, as a JSON array of its elements.
+    Span(Box<JsonType>),
+    /// `Option<T>`, `None` being `null`.
+    Option(Box<JsonType>),
+    /// Other named types, which (de)serialize themselves.
+    Nested,
+}
+
+impl JsonType {
+    /// Maps a field type to its JSON representation, `None` if the type isn't supported.
+    fn parse(type_str: &str) -> Option<JsonType> {
+        // Clean up and normalize Cairo type strings
+        let clean = type_str
+            .trim()
+            .replace(" ", "")
+            .replace("core::", "")
+            .replace("byte_array::", "")
+            .replace("array::", "")
+            .replace("option::", "");
+        Self::parse_clean(&clean)
+    }
+
+    fn parse_clean(clean: &str) -> Option<JsonType> {
+        if let Some(inner) = generic_arg(clean, "Option") {
+            return Some(JsonType::Option(Box::new(Self::parse_clean(inner)?)));
         }
-        "bool" => {
-            format!("let {field_name} = alexandria_json::json::JsonValueHelper::as_bool(@alexandria_json::json::JsonValueHelper::get_object_field(@value, \"{field_name}\")?)?;")
+        if let Some(inner) = generic_arg(clean, "Array") {
+            return Some(JsonType::Array(Box::new(Self::parse_clean(inner)?)));
         }
-        "Array<ByteArray>" => {
-            format!(
-                "let {field_name}_json = alexandria_json::json::JsonValueHelper::get_object_field(@value, \"{field_name}\")?;\n        let {field_name}_array = alexandria_json::json::JsonValueHelper::as_array(@{field_name}_json)?;\n        let mut {field_name} = array![];\n        let mut {field_name}_i = 0;\n        while {field_name}_i < {field_name}_array.len() {{\n            let {field_name}_item = alexandria_json::json::JsonValueHelper::as_string({field_name}_array.at({field_name}_i))?;\n            {field_name}.append({field_name}_item);\n            {field_name}_i += 1;\n        }};"
-            )
+        if let Some(inner) = generic_arg(clean, "Span") {
+            return Some(JsonType::Span(Box::new(Self::parse_clean(inner)?)));
         }
-        "u256" => {
-            format!("let {field_name} = alexandria_json::json::JsonValueHelper::as_u256(@alexandria_json::json::JsonValueHelper::get_object_field(@value, \"{field_name}\")?)?;")
+
+        if clean == "felt252" {
+            Some(JsonType::Felt252)
+        } else if SIGNED_INT_TYPES.contains(&clean) {
+            Some(JsonType::SignedInt)
+        } else if UNSIGNED_INT_TYPES.contains(&clean)
+            || STARKNET_TYPES
+                .iter()
+                .any(|ty| clean == *ty || clean.ends_with(&format!("::{ty}")))
+        {
+            Some(JsonType::Int)
+        } else if clean == "bool" {
+            Some(JsonType::Bool)
+        } else if clean == "u256" {
+            Some(JsonType::U256)
+        } else if clean == "Decimal" || clean.ends_with("decimal::Decimal") {
+            Some(JsonType::Decimal)
+        } else if clean == "ByteArray" {
+            Some(JsonType::ByteArray)
+        } else if is_nested_type(clean) {
+            Some(JsonType::Nested)
+        } else {
+            None
         }
-        "Decimal" => {
-            format!("let {field_name} = alexandria_json::json::JsonValueHelper::as_decimal(@alexandria_json::json::JsonValueHelper::get_object_field(@value, \"{field_name}\")?)?;")
+    }
+
+    /// Expression converting `value`, a snapshot of this type, to a `JsonValue`. `depth`
+    /// keeps the names of nested bindings unique.
+    fn serialize_expr(&self, value: &str, depth: usize) -> String {
+        match self {
+            JsonType::Felt252 => format!("alexandria_json::json::JsonValue::Number(*{value})"),
+            JsonType::Int => {
+                format!("alexandria_json::json::JsonValue::Number((*{value}).into())")
+            }
+            JsonType::SignedInt => {
+                format!("alexandria_json::json::JsonValue::SignedNumber((*{value}).into())")
+            }
+            JsonType::Bool => format!("alexandria_json::json::JsonValue::Bool(*{value})"),
+            JsonType::U256 => {
+                format!("alexandria_json::json::JsonValue::Number((*{value}).try_into().unwrap())")
+            }
+            JsonType::Decimal => format!("alexandria_json::json::JsonValue::Decimal(*{value})"),
+            JsonType::ByteArray => {
+                format!("alexandria_json::json::JsonValue::String({value}.clone())")
+            }
+            JsonType::Array(item) | JsonType::Span(item) => {
+                let span = match self {
+                    JsonType::Array(_) => format!("{value}.span()"),
+                    _ => format!("*{value}"),
+                };
+                format!(
+                    "{{
+            let mut items_{depth} = array![];
+            for item_{depth} in {span} {{
+                items_{depth}.append({});
+            }};
+            alexandria_json::json::JsonValue::Array(items_{depth})
+        }}",
+                    item.serialize_expr(&format!("item_{depth}"), depth + 1)
+                )
+            }
+            JsonType::Option(inner) => format!(
+                "match {value} {{
+            Option::Some(value_{depth}) => {},
+            Option::None => alexandria_json::json::JsonValue::Null,
+        }}",
+                inner.serialize_expr(&format!("value_{depth}"), depth + 1)
+            ),
+            JsonType::Nested => format!("alexandria_json::json::JsonSerialize::to_json({value})"),
         }
-        _ => {
-            // Default to ByteArray/string
-            format!("let {field_name} = alexandria_json::json::JsonValueHelper::as_string(@alexandria_json::json::JsonValueHelper::get_object_field(@value, \"{field_name}\")?)?;")
+    }
+
+    /// Expression converting `value`, a `JsonValue` variable, to this type, returning the
+    /// error if it doesn't match. `depth` keeps the names of nested bindings unique.
+    fn deserialize_expr(&self, value: &str, depth: usize) -> String {
+        let helper =
+            |name: &str| format!("alexandria_json::json::JsonValueHelper::{name}(@{value})?");
+        match self {
+            JsonType::Felt252 => helper("as_number"),
+            JsonType::Int | JsonType::SignedInt => helper("as_int"),
+            JsonType::Bool => helper("as_bool"),
+            JsonType::U256 => helper("as_u256"),
+            JsonType::Decimal => helper("as_decimal"),
+            JsonType::ByteArray => helper("as_string"),
+            JsonType::Array(item) | JsonType::Span(item) => {
+                let items = match self {
+                    JsonType::Array(_) => format!("items_{depth}"),
+                    _ => format!("items_{depth}.span()"),
+                };
+                format!(
+                    "{{
+            let mut items_{depth} = array![];
+            for item_{depth} in {} {{
+                items_{depth}.append({});
+            }};
+            {items}
+        }}",
+                    helper("as_array"),
+                    item.deserialize_expr(&format!("item_{depth}"), depth + 1)
+                )
+            }
+            JsonType::Option(inner) => format!(
+                "if alexandria_json::json::JsonValueHelper::is_null(@{value}) {{
+            Option::None
+        }} else {{
+            Option::Some({})
+        }}",
+                inner.deserialize_expr(value, depth + 1)
+            ),
+            JsonType::Nested => {
+                format!("alexandria_json::json::JsonDeserialize::from_json({value})?")
+            }
         }
     }
 }
 
-fn normalize_type(type_str: &str) -> String {
-    // Clean up and normalize Cairo type strings
-    let clean = type_str
-        .trim()
-        .replace(" ", "")
-        .replace("core::", "")
-        .replace("byte_array::", "")
-        .replace("array::", "");
-
-    // Handle common patterns
-    if clean.contains("Array<ByteArray>") || clean.contains("Array<byte_array::ByteArray>") {
-        "Array<ByteArray>".to_string()
-    } else if clean == "felt252" {
-        "felt252".to_string()
-    } else if clean == "bool" {
-        "bool".to_string()
-    } else if clean == "u256" {
-        "u256".to_string()
-    } else if clean == "Decimal"
-        || clean.contains("decimal::Decimal")
-        || clean.contains("parser::decimal::Decimal")
-    {
-        "Decimal".to_string()
-    } else if clean == "ByteArray" || clean.is_empty() {
-        "ByteArray".to_string()
-    } else {
-        // Default to ByteArray for unknown types
-        "ByteArray".to_string()
-    }
+/// The argument of a generic type called `name`, e.g. `u8` for `Option<u8>`.
+fn generic_arg<'a>(clean: &'a str, name: &str) -> Option<&'a str> {
+    clean
+        .strip_prefix(name)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
 }
 
-fn generate_field_serialization(field: &FieldInfo) -> String {
-    let field_name = &field.name;
-    let field_type = &field.field_type;
-    let normalized = normalize_type(field_type);
+/// Whether a type is a named type, e.g. `User` or `models::Pair<u8>`, that isn't an array.
+/// Arrays of unsupported elements are rejected rather than treated as a named type.
+/// Tuples, fixed-size arrays and snapshots don't implement the JSON traits.
+fn is_nested_type(clean: &str) -> bool {
+    clean.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && clean
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_:<>,".contains(c))
+        && !clean.starts_with("Array<")
+        && !clean.starts_with("Span<")
+}
 
-    // For debugging - generate a comment showing what type was detected
-    let debug_comment =
-        format!("// Field: {field_name}, Type: {field_type}, Normalized: {normalized}");
+/// Case convention of the JSON keys, set by the `#[json(rename_all = "...")]` struct attribute.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
 
-    let serialization_code = match normalized.as_str() {
-        "felt252" => {
-            format!(
-                "fields.append((\"{field_name}\", alexandria_json::json::JsonValue::Number(*self.{field_name})));"
-            )
-        }
-        "bool" => {
-            format!(
-                "fields.append((\"{field_name}\", alexandria_json::json::JsonValue::Bool(*self.{field_name})));"
-            )
-        }
-        "Array<ByteArray>" => {
-            format!(
-                "let mut {field_name}_json = array![];\n        let mut {field_name}_i = 0;\n        while {field_name}_i < self.{field_name}.len() {{\n            {field_name}_json.append(alexandria_json::json::JsonValue::String(self.{field_name}.at({field_name}_i).clone()));\n            {field_name}_i += 1;\n        }};\n        fields.append((\"{field_name}\", alexandria_json::json::JsonValue::Array({field_name}_json)));"
-            )
-        }
-        "u256" => {
-            format!("fields.append((\"{field_name}\", alexandria_json::json::JsonValue::Number((*self.{field_name}).try_into().unwrap())));")
+const RENAME_RULES: [(&str, RenameRule); 8] = [
+    ("lowercase", RenameRule::Lower),
+    ("UPPERCASE", RenameRule::Upper),
+    ("PascalCase", RenameRule::Pascal),
+    ("camelCase", RenameRule::Camel),
+    ("snake_case", RenameRule::Snake),
+    ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+    ("kebab-case", RenameRule::Kebab),
+    ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+];
+
+impl RenameRule {
+    /// Key of the snake case `field_name` in this convention, e.g. `userId` for `user_id`.
+    fn apply(self, field_name: &str) -> String {
+        let words = field_name.split('_').filter(|word| !word.is_empty());
+        let capitalize = |word: &str| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        };
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field_name.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field_name.to_uppercase(),
+            RenameRule::Pascal => words.map(capitalize).collect(),
+            RenameRule::Camel => words
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_string()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Kebab => words.collect::<Vec<_>>().join("-"),
+            RenameRule::ScreamingKebab => words.collect::<Vec<_>>().join("-").to_uppercase(),
         }
-        "Decimal" => {
-            format!(
-                "fields.append((\"{field_name}\", alexandria_json::json::JsonValue::Decimal(*self.{field_name})));"
-            )
+    }
+}
+
+/// Parses the `#[json(rename_all = "...")]` attribute of the struct.
+fn rename_rule(s: &ItemInfo) -> Result<Option<RenameRule>, Diagnostics> {
+    let Some(attr) = find_attribute(&s.attributes, "json") else {
+        return Ok(None);
+    };
+    let expected = || {
+        let rules = RENAME_RULES
+            .iter()
+            .map(|(name, _)| format!("`\"{name}\"`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("expected `#[json(rename_all = \"...\")]` with one of {rules}")
+    };
+    match attr.args.as_slice() {
+        [arg] if arg.name.as_deref() == Some("rename_all") => {
+            let rule = arg.as_string().and_then(|value| {
+                RENAME_RULES
+                    .iter()
+                    .find(|(name, _)| *name == value)
+                    .map(|(_, rule)| *rule)
+            });
+            rule.map(Some).ok_or_else(|| arg.error(expected()).into())
         }
-        _ => {
-            // Default to ByteArray/string
-            format!("fields.append((\"{field_name}\", alexandria_json::json::JsonValue::String(self.{field_name}.clone())));")
+        _ => Err(attr.error(expected()).into()),
+    }
+}
+
+/// Options set by the `#[json(...)]` attribute of a field.
+struct FieldOptions {
+    /// Key of the field in the JSON object.
+    key: String,
+    /// Other keys the field is deserialized from, tried after `key`.
+    aliases: Vec<String>,
+    /// Leave the field out of the JSON, it's deserialized as `Default::default()`.
+    skip: bool,
+    /// Deserialize a missing key as `Default::default()` instead of failing.
+    default: bool,
+    /// Omit the key of a `None` field instead of writing `null`.
+    skip_if_none: bool,
+}
+
+/// Parses the `#[json(...)]` attribute of a field, its key defaulting to the field name
+/// converted with the struct `rename_rule`.
+fn field_options(
+    field: &FieldInfo,
+    rename_rule: Option<RenameRule>,
+) -> Result<FieldOptions, Diagnostic> {
+    let mut options = FieldOptions {
+        key: rename_rule.map_or_else(|| field.name.clone(), |rule| rule.apply(&field.name)),
+        aliases: Vec::new(),
+        skip: false,
+        default: false,
+        skip_if_none: false,
+    };
+    let Some(attr) = find_attribute(&field.attributes, "json") else {
+        return Ok(options);
+    };
+    if attr.args.is_empty() {
+        return Err(attr.error("expected e.g. `#[json(rename = \"id\")]`"));
+    }
+    for arg in &attr.args {
+        let string_value = || {
+            arg.as_string().ok_or_else(|| {
+                arg.error(format!(
+                    "expected a string, e.g. `{} = \"id\"`",
+                    arg.name.as_deref().unwrap_or_default()
+                ))
+            })
+        };
+        match (arg.name.as_deref(), arg.value.as_str()) {
+            (Some("rename"), _) => options.key = string_value()?,
+            (Some("alias"), _) => options.aliases.push(string_value()?),
+            (None, "skip") => options.skip = true,
+            (None, "default") => options.default = true,
+            (None, "skip_if_none") => options.skip_if_none = true,
+            _ => {
+                return Err(arg.error(
+                    "expected `rename = \"...\"`, `alias = \"...\"`, `skip`, `default` or `skip_if_none`",
+                ))
+            }
         }
+    }
+    Ok(options)
+}
+
+/// Maps the type of a field that isn't skipped to its JSON representation, checking that
+/// its options apply to it.
+fn field_json_type(
+    derive_name: &str,
+    field: &FieldInfo,
+    options: &FieldOptions,
+) -> Result<JsonType, Diagnostic> {
+    let Some(ty) = JsonType::parse(&field.field_type) else {
+        return Err(unsupported_type_error(derive_name, field));
     };
+    if options.skip_if_none && !matches!(ty, JsonType::Option(_)) {
+        let attr = find_attribute(&field.attributes, "json").unwrap();
+        return Err(attr.error("`skip_if_none` only applies to `Option` fields"));
+    }
+    Ok(ty)
+}
 
-    format!("{debug_comment}\n        {serialization_code}")
+/// Cairo string literal of `value`.
+fn string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
-fn generate_json_deserialize_impl(s: &StructInfo) -> String {
+fn generate_field_deserialization(
+    field: &FieldInfo,
+    rename_rule: Option<RenameRule>,
+) -> Result<String, Diagnostic> {
+    let field_name = &field.name;
+    let field_type = &field.field_type;
+
+    let options = field_options(field, rename_rule)?;
+    if options.skip {
+        return Ok(format!(
+            "let {field_name}: {field_type} = Default::default();"
+        ));
+    }
+    let ty = field_json_type("JsonDeserialize", field, &options)?;
+
+    let lookup = if options.aliases.is_empty() {
+        format!(
+            "alexandria_json::json::JsonValueHelper::get_object_field(@value, {})",
+            string_literal(&options.key)
+        )
+    } else {
+        let keys = std::iter::once(&options.key)
+            .chain(&options.aliases)
+            .map(|key| string_literal(key))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "alexandria_json::json::JsonValueHelper::get_any_object_field(@value, array![{keys}].span())"
+        )
+    };
+    // `null` and a missing key are both `None`
+    let missing_value = match ty {
+        _ if options.default => Some("Default::default()"),
+        JsonType::Option(_) => Some("Option::None"),
+        _ => None,
+    };
+
+    let value = ty.deserialize_expr(&format!("{field_name}_json"), 0);
+    let deserialization_code = match missing_value {
+        Some(missing_value) => format!(
+            "let {field_name}: {field_type} = match {lookup} {{
+            Result::Ok({field_name}_json) => {value},
+            Result::Err(alexandria_json::json::JsonError::MissingField) => {missing_value},
+            Result::Err(error) => {{
+                return Result::Err(error);
+            }},
+        }};"
+        ),
+        None => format!(
+            "let {field_name}_json = {lookup}?;
+        let {field_name}: {field_type} = {value};"
+        ),
+    };
+
+    Ok(deserialization_code)
+}
+
+fn unsupported_type_error(derive_name: &str, field: &FieldInfo) -> Diagnostic {
+    Diagnostic::span_error(
+        field.type_span.clone(),
+        format!(
+            "`{derive_name}` does not support the type `{}` of field `{}`, expected {SUPPORTED_TYPES}, or a type implementing `{derive_name}`",
+            field.field_type, field.name
+        ),
+    )
+}
+
+fn generate_field_serialization(
+    field: &FieldInfo,
+    rename_rule: Option<RenameRule>,
+) -> Result<String, Diagnostic> {
+    let field_name = &field.name;
+
+    let options = field_options(field, rename_rule)?;
+    if options.skip {
+        return Ok(String::new());
+    }
+    let ty = field_json_type("JsonSerialize", field, &options)?;
+    let key = string_literal(&options.key);
+
+    let serialization_code = match &ty {
+        JsonType::Option(inner) if options.skip_if_none => format!(
+            "match self.{field_name} {{
+            Option::Some({field_name}_value) => {{
+                fields.append(({key}, {}));
+            }},
+            Option::None => {{}},
+        }};",
+            inner.serialize_expr(&format!("{field_name}_value"), 1)
+        ),
+        _ => format!(
+            "fields.append(({key}, {}));",
+            ty.serialize_expr(&format!("self.{field_name}"), 0)
+        ),
+    };
+
+    Ok(serialization_code)
+}
+
+/// Generates the code for every field, attributed to that field, and collects the errors
+/// of all unsupported fields. Fields without code, e.g. skipped ones, leave nothing.
+fn generate_fields(
+    fields: &[FieldInfo],
+    generate: impl Fn(&FieldInfo) -> Result<String, Diagnostic>,
+) -> Result<Vec<CodeBuilder>, Diagnostics> {
+    let mut code = Vec::new();
+    let mut errors = Vec::new();
+    for field in fields {
+        match generate(field) {
+            Ok(field_code) if field_code.is_empty() => {}
+            Ok(field_code) => code.push(spanned(field_code, &field.span)),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(code)
+    } else {
+        Err(Diagnostics::new(errors))
+    }
+}
+
+fn generate_json_deserialize_impl(
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+) -> Result<CodeBuilder, Diagnostics> {
     // Generate actual JSON field extraction using intelligent type detection
-    let field_extractions = s
-        .fields
-        .iter()
-        .map(generate_field_deserialization)
-        .collect::<Vec<_>>()
-        .join("\n        ");
+    let rename_rule = rename_rule(s)?;
+    let field_extractions = generate_fields(fields, |field| {
+        generate_field_deserialization(field, rename_rule)
+    })?;
 
     // Generate struct construction
-    let struct_fields = s
-        .fields
-        .iter()
-        .map(|field| field.name.clone())
-        .collect::<Vec<_>>()
-        .join(", ");
+    let struct_fields = fields.iter().map(|field| spanned(&field.name, &field.span));
 
     // Generate the implementation following the exact working pattern from existing implementations
-    format!(
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "
+pub impl {0}JsonDeserializeImpl of alexandria_json::json::JsonDeserialize<{0}> {{
+    fn from_json(value: alexandria_json::json::JsonValue) -> Result<{0}, alexandria_json::json::JsonError> {{
+        ",
+        s.name
+    ));
+    code.push_joined(field_extractions, "\n        ");
+    code.push(format!(
         "
-pub impl {}JsonDeserializeImpl of alexandria_json::json::JsonDeserialize<{}> {{
-    fn from_json(value: alexandria_json::json::JsonValue) -> Result<{}, alexandria_json::json::JsonError> {{
-        {}
         
-        Result::Ok({} {{ {} }})
-    }}
-}}",
-        s.name,
-        s.name,
-        s.name,
-        field_extractions,
-        s.name,
-        struct_fields
-    )
+        Result::Ok({} {{ ",
+        s.name
+    ));
+    code.push_joined(struct_fields, ", ");
+    code.push(
+        " })
+    }
+}",
+    );
+    Ok(code)
 }
 
 /// Automatically implements the `alexandria_json::json::JsonDeserialize` trait.
@@ -147,10 +530,23 @@ pub impl {}JsonDeserializeImpl of alexandria_json::json::JsonDeserialize<{}> {{
 /// Type detection is based on actual Cairo type information from the AST:
 /// - `ByteArray` fields → `as_string()`
 /// - `felt252` fields → `as_number()`
+/// - `u8`..`u128`, `usize`, `i8`..`i128`, `ContractAddress`, `EthAddress`, `ClassHash` fields →
+///   `as_int()`, failing with `JsonError::OutOfRange` if the number doesn't fit
 /// - `bool` fields → `as_bool()`
 /// - `Decimal` fields → `as_decimal()` for fixed-point numbers
-/// - `Array<ByteArray>` fields → `as_array()` with string element conversion
-/// - Other types → defaults to `as_string()`
+/// - `Array<T>` and `Span<T>` fields → `as_array()` with every element converted as a `T`,
+///   including nested arrays
+/// - `Option<T>` fields → `None` for `null` or a missing key, `Some` of the `T` value otherwise
+/// - Other named types, e.g. `i257` or structs deriving `JsonDeserialize` → their own
+///   `from_json()`
+/// - Other types are rejected with an error on the field type
+///
+/// Keys are customized by the `#[json(rename_all = "camelCase")]` struct attribute, which
+/// converts every field name to a case convention, and by the `#[json(...)]` field attribute:
+/// - `rename = "key"` → the key of the field, taking precedence over `rename_all`
+/// - `alias = "key"` → another key the field is read from, can be repeated
+/// - `skip` → the field isn't read and is set to `Default::default()`
+/// - `default` → a missing key sets the field to `Default::default()`
 ///
 /// ```
 /// #[derive(JsonDeserialize, Drop, Clone, PartialEq, Debug)]
@@ -168,32 +564,41 @@ pub impl {}JsonDeserializeImpl of alexandria_json::json::JsonDeserialize<{}> {{
 /// ```
 #[derive_macro]
 pub fn json_deserialize(token_stream: TokenStream) -> ProcMacroResult {
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_json_deserialize_impl(&s)))
+    expand_struct_derive(
+        token_stream,
+        "JsonDeserialize",
+        generate_json_deserialize_impl,
+    )
 }
 
-fn generate_json_serialize_impl(s: &StructInfo) -> String {
+fn generate_json_serialize_impl(
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+) -> Result<CodeBuilder, Diagnostics> {
     // Generate field serialization code using intelligent type detection
-    let field_serializations = s
-        .fields
-        .iter()
-        .map(generate_field_serialization)
-        .collect::<Vec<_>>()
-        .join("\n        ");
+    let rename_rule = rename_rule(s)?;
+    let field_serializations = generate_fields(fields, |field| {
+        generate_field_serialization(field, rename_rule)
+    })?;
 
     // Generate the implementation
-    format!(
+    let mut code = CodeBuilder::default();
+    code.push(format!(
         "
-pub impl {}JsonSerializeImpl of alexandria_json::json::JsonSerialize<{}> {{
-    fn to_json(self: @{}) -> alexandria_json::json::JsonValue {{
+pub impl {0}JsonSerializeImpl of alexandria_json::json::JsonSerialize<{0}> {{
+    fn to_json(self: @{0}) -> alexandria_json::json::JsonValue {{
         let mut fields = array![];
-        {}
+        ",
+        s.name
+    ));
+    code.push_joined(field_serializations, "\n        ");
+    code.push(
+        "
         alexandria_json::json::JsonValue::Object(fields)
-    }}
-}}",
-        s.name, s.name, s.name, field_serializations
-    )
+    }
+}",
+    );
+    Ok(code)
 }
 
 /// Automatically implements the `alexandria_json::json::JsonSerialize` trait.
@@ -204,10 +609,22 @@ pub impl {}JsonSerializeImpl of alexandria_json::json::JsonSerialize<{}> {{
 /// Type detection is based on actual Cairo type information from the AST:
 /// - `ByteArray` fields → `JsonValue::String`
 /// - `felt252` fields → `JsonValue::Number`
+/// - `u8`..`u128`, `usize`, `ContractAddress`, `EthAddress`, `ClassHash` fields →
+///   `JsonValue::Number`
+/// - `i8`..`i128` fields → `JsonValue::SignedNumber`, written with a minus sign when negative
 /// - `bool` fields → `JsonValue::Bool`
 /// - `Decimal` fields → `JsonValue::Decimal` for fixed-point numbers
-/// - `Array<ByteArray>` fields → `JsonValue::Array` with string elements
-/// - Other types → defaults to `JsonValue::String`
+/// - `Array<T>` and `Span<T>` fields → `JsonValue::Array` with every element converted as a
+///   `T`, including nested arrays
+/// - `Option<T>` fields → `JsonValue::Null` for `None`, or no key at all with the
+///   `#[json(skip_if_none)]` field attribute, the `T` value otherwise
+/// - Other named types, e.g. `i257` or structs deriving `JsonSerialize` → their own
+///   `to_json()`
+/// - Other types are rejected with an error on the field type
+///
+/// Keys are customized by the `#[json(rename_all = "camelCase")]` struct attribute, which
+/// converts every field name to a case convention, and by the `#[json(rename = "key")]` field
+/// attribute, taking precedence over it. `#[json(skip)]` leaves a field out of the object.
 ///
 /// ```
 /// #[derive(JsonSerialize, Drop, Clone, PartialEq, Debug)]
@@ -226,7 +643,5 @@ pub impl {}JsonSerializeImpl of alexandria_json::json::JsonSerialize<{}> {{
 /// ```
 #[derive_macro]
 pub fn json_serialize(token_stream: TokenStream) -> ProcMacroResult {
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_json_serialize_impl(&s)))
+    expand_struct_derive(token_stream, "JsonSerialize", generate_json_serialize_impl)
 }
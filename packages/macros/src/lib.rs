@@ -1,8 +1,17 @@
+mod arithmetic_traits;
+mod bounded_trait;
+mod const_eval;
+mod fixed_point;
 mod generate_events;
+mod int_literals;
 mod json_derive;
 mod num_traits;
+mod one_trait;
+mod partial_ord;
 mod pow;
 mod zero_trait;
 
+mod codegen;
+mod int_types;
 mod parse;
 mod token_tree_parser;
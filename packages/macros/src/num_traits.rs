@@ -1,6 +1,15 @@
-use cairo_lang_macro::{derive_macro, ProcMacroResult, TokenStream};
+use cairo_lang_macro::{
+    derive_macro, executable_attribute, Diagnostic, Diagnostics, ProcMacroResult, TokenStream,
+};
 
-use crate::parse::{parse_struct_info, StructInfo};
+use crate::codegen::{spanned, CodeBuilder};
+use crate::parse::{expand_struct_derive, find_attribute, FieldInfo, ItemInfo};
+
+// Declares the `#[op]` helper attribute so that the compiler accepts it on members.
+executable_attribute!("op");
+
+// Declares the `#[scalar]` helper attribute so that the compiler accepts it on structs.
+executable_attribute!("scalar");
 
 struct OpInfo {
     trait_name: String,
@@ -8,88 +17,311 @@ struct OpInfo {
     operator: String,
 }
 
-fn generate_op_trait_impl(op_info: &OpInfo, s: &StructInfo) -> String {
-    let generic_params = s
-        .generic_params
-        .as_ref()
-        .map_or(String::new(), |params| format!("<{}>", params.join(", ")));
-
-    let trait_bounds = s
-        .generic_params
-        .as_ref()
-        .map_or_else(String::new, |params| {
-            let bounds = params
-                .iter()
-                .flat_map(|param| {
-                    vec![
-                        format!("+core::traits::{}<{}>", op_info.trait_name, param),
-                        format!("+core::traits::Drop<{}>", param),
-                    ]
-                })
-                .collect::<Vec<_>>()
-                .join(",\n");
-            format!("<{},\n{}>", params.join(", "), bounds)
-        });
+/// How a derived operator treats a member, chosen with the `#[op(...)]` field attribute.
+#[derive(Clone, Copy, PartialEq)]
+enum FieldMode {
+    /// The operator is applied to the member.
+    Apply,
+    /// `#[op(skip)]`: the member is taken from the left operand as is.
+    Skip,
+    /// `#[op(assert_eq)]`: the member must be equal in both operands and is taken from the left
+    /// one. Unary and scalar operators have a single operand of the struct and just take it.
+    AssertEq,
+}
+
+/// Modes of the `fields`, reporting every invalid `#[op(...)]` attribute.
+fn field_modes(fields: &[FieldInfo]) -> Result<Vec<FieldMode>, Diagnostics> {
+    let mut modes = Vec::new();
+    let mut errors = Vec::new();
+    for field in fields {
+        let Some(attr) = find_attribute(&field.attributes, "op") else {
+            modes.push(FieldMode::Apply);
+            continue;
+        };
+        match attr.args.as_slice() {
+            [arg] if arg.name.is_none() && arg.value == "skip" => modes.push(FieldMode::Skip),
+            [arg] if arg.name.is_none() && arg.value == "assert_eq" => {
+                modes.push(FieldMode::AssertEq)
+            }
+            _ => errors.push(attr.error("expected `#[op(skip)]` or `#[op(assert_eq)]`")),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(modes)
+    } else {
+        Err(Diagnostics::new(errors))
+    }
+}
+
+/// Generic parameters of an operator impl: `op_bound` for the generic types of the members the
+/// operator is applied to, `PartialEq` for the ones compared and `Drop` for all of them.
+fn op_impl_generic_params(
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+    modes: &[FieldMode],
+    op_bound: impl Fn(&str) -> String,
+) -> String {
+    let mut bounds = Vec::new();
+    for (field, mode) in fields.iter().zip(modes) {
+        for ty in s.generic_field_types(std::slice::from_ref(field)) {
+            match mode {
+                FieldMode::Apply => bounds.push(op_bound(ty)),
+                FieldMode::Skip => {}
+                FieldMode::AssertEq => bounds.push(format!("+core::traits::PartialEq<{}>", ty)),
+            }
+            bounds.push(format!("+core::traits::Drop<{}>", ty));
+        }
+    }
+    s.impl_generic_params(&bounds)
+}
 
-    let members_op = s
-        .members
+/// Assertions that the `#[op(assert_eq)]` members of `lhs` and `rhs` are equal.
+fn assert_eq_members(
+    fields: &[FieldInfo],
+    modes: &[FieldMode],
+    lhs: &str,
+    rhs: &str,
+) -> Vec<CodeBuilder> {
+    fields
         .iter()
-        .map(|member| format!("{0}: lhs.{0} {1} rhs.{0}", member, op_info.operator))
-        .collect::<Vec<_>>()
-        .join(", ");
+        .zip(modes)
+        .filter(|(_, mode)| **mode == FieldMode::AssertEq)
+        .map(|(field, _)| {
+            spanned(
+                format!(
+                    "assert!({1}.{0} == {2}.{0}, \"`{0}` must be equal in both operands\");",
+                    field.name, lhs, rhs
+                ),
+                &field.span,
+            )
+        })
+        .collect()
+}
+
+fn generate_op_trait_impl(
+    op_info: &OpInfo,
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+) -> Result<CodeBuilder, Diagnostics> {
+    let modes = field_modes(fields)?;
+    let generic_params = s.generic_args();
+    let trait_bounds = op_impl_generic_params(s, fields, &modes, |ty| {
+        format!("+core::traits::{}<{}>", op_info.trait_name, ty)
+    });
+
+    let members_op = fields.iter().zip(&modes).map(|(field, mode)| {
+        let code = match mode {
+            FieldMode::Apply => format!("{0}: lhs.{0} {1} rhs.{0}", field.name, op_info.operator),
+            FieldMode::Skip | FieldMode::AssertEq => format!("{0}: lhs.{0}", field.name),
+        };
+        spanned(code, &field.span)
+    });
 
-    format!(
+    let mut code = CodeBuilder::default();
+    code.push(format!(
         "\n
 impl {0}{1}{2}
 of core::traits::{1}<{0}{3}> {{
     fn {4}(lhs: {0}{3}, rhs: {0}{3}) -> {0}{3} {{
-        {0} {{ {5} }}
-    }}
-}}\n",
-        s.name, op_info.trait_name, trait_bounds, generic_params, op_info.fn_name, members_op
-    )
-}
-
-fn generate_op_assign_trait_impl(op_info: &OpInfo, s: &StructInfo) -> String {
-    let generic_params = s
-        .generic_params
-        .as_ref()
-        .map_or(String::new(), |params| format!("<{}>", params.join(", ")));
-
-    let trait_bounds = s
-        .generic_params
-        .as_ref()
-        .map_or_else(String::new, |params| {
-            let bounds = params
-                .iter()
-                .flat_map(|param| {
-                    vec![
-                        format!("+core::ops::{0}Assign<{1}, {1}>", op_info.trait_name, param),
-                        format!("+core::traits::Drop<{}>", param),
-                    ]
-                })
-                .collect::<Vec<_>>()
-                .join(",\n");
-            format!("<{},\n{}>", params.join(", "), bounds)
-        });
+        ",
+        s.name, op_info.trait_name, trait_bounds, generic_params, op_info.fn_name
+    ));
+    for assertion in assert_eq_members(fields, &modes, "lhs", "rhs") {
+        code.append(assertion);
+        code.push("\n        ");
+    }
+    code.push(format!("{} {{ ", s.name));
+    code.push_joined(members_op, ", ");
+    code.push(" }\n    }\n}\n");
+    Ok(code)
+}
 
-    let members_op = s
-        .members
+fn generate_op_assign_trait_impl(
+    op_info: &OpInfo,
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+) -> Result<CodeBuilder, Diagnostics> {
+    let modes = field_modes(fields)?;
+    let generic_params = s.generic_args();
+    let trait_bounds = op_impl_generic_params(s, fields, &modes, |ty| {
+        format!("+core::ops::{0}Assign<{1}, {1}>", op_info.trait_name, ty)
+    });
+
+    let members_op = fields
         .iter()
-        .map(|member| format!("self.{0} {1}= rhs.{0}", member, op_info.operator))
-        .collect::<Vec<_>>()
-        .join(";\n        ");
+        .zip(&modes)
+        .filter(|(_, mode)| **mode == FieldMode::Apply)
+        .map(|(field, _)| {
+            spanned(
+                format!("self.{0} {1}= rhs.{0};", field.name, op_info.operator),
+                &field.span,
+            )
+        });
 
-    format!(
+    let mut code = CodeBuilder::default();
+    code.push(format!(
         "\n
 impl {0}{1}Assign{2}
 of core::ops::{1}Assign<{0}{3}, {0}{3}> {{
     fn {4}_assign(ref self: {0}{3}, rhs: {0}{3}) {{
-        {5};
-    }}
-}}\n",
-        s.name, op_info.trait_name, trait_bounds, generic_params, op_info.fn_name, members_op
-    )
+        ",
+        s.name, op_info.trait_name, trait_bounds, generic_params, op_info.fn_name
+    ));
+    code.push_joined(
+        assert_eq_members(fields, &modes, "self", "rhs")
+            .into_iter()
+            .chain(members_op),
+        "\n        ",
+    );
+    code.push("\n    }\n}\n");
+    Ok(code)
+}
+
+fn generate_unary_op_trait_impl(
+    op_info: &OpInfo,
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+) -> Result<CodeBuilder, Diagnostics> {
+    let modes = field_modes(fields)?;
+    let generic_params = s.generic_args();
+    let trait_bounds = op_impl_generic_params(s, fields, &modes, |ty| {
+        format!("+core::traits::{}<{}>", op_info.trait_name, ty)
+    });
+
+    let members_op = fields.iter().zip(&modes).map(|(field, mode)| {
+        let code = match mode {
+            FieldMode::Apply => format!("{0}: {1}a.{0}", field.name, op_info.operator),
+            FieldMode::Skip | FieldMode::AssertEq => format!("{0}: a.{0}", field.name),
+        };
+        spanned(code, &field.span)
+    });
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+impl {0}{1}{2}
+of core::traits::{1}<{0}{3}> {{
+    fn {4}(a: {0}{3}) -> {0}{3} {{
+        {0} {{ ",
+        s.name, op_info.trait_name, trait_bounds, generic_params, op_info.fn_name
+    ));
+    code.push_joined(members_op, ", ");
+    code.push(" }\n    }\n}\n");
+    Ok(code)
+}
+
+/// Type of the scalar given by the `#[scalar(<type>)]` attribute of the item.
+fn scalar_type(s: &ItemInfo, derive_name: &str) -> Result<String, Diagnostics> {
+    let Some(attr) = find_attribute(&s.attributes, "scalar") else {
+        return Err(Diagnostic::span_error(
+            s.span.clone(),
+            format!("`{derive_name}` requires a `#[scalar(<type>)]` attribute on the struct"),
+        )
+        .into());
+    };
+    match attr.args.as_slice() {
+        [arg] if arg.name.is_none() => Ok(arg.value.clone()),
+        _ => Err(attr
+            .error("expected a single scalar type, e.g. `#[scalar(u64)]`")
+            .into()),
+    }
+}
+
+/// Statements applying the scalar operator to the members of `target` the operator applies to.
+fn scalar_members_op(
+    op_info: &OpInfo,
+    fields: &[FieldInfo],
+    modes: &[FieldMode],
+    target: &str,
+) -> Vec<CodeBuilder> {
+    fields
+        .iter()
+        .zip(modes)
+        .filter(|(_, mode)| **mode == FieldMode::Apply)
+        .map(|(field, _)| {
+            spanned(
+                format!("{0}.{1} {2}= rhs;", target, field.name, op_info.operator),
+                &field.span,
+            )
+        })
+        .collect()
+}
+
+fn generate_scalar_op_trait_impl(
+    op_info: &OpInfo,
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+    scalar: &str,
+) -> Result<CodeBuilder, Diagnostics> {
+    let modes = field_modes(fields)?;
+    let generic_params = s.generic_args();
+    let trait_bounds = op_impl_generic_params(s, fields, &modes, |ty| {
+        format!(
+            "+core::ops::{}Assign<{}, {}>",
+            op_info.trait_name, ty, scalar
+        )
+    });
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+{5}trait {0}Scalar{1}{6} {{
+    fn scalar_{4}(self: {0}{3}, rhs: {7}) -> {0}{3};
+}}
+
+impl {0}Scalar{1}Impl{2}
+of {0}Scalar{1}{3} {{
+    fn scalar_{4}(self: {0}{3}, rhs: {7}) -> {0}{3} {{
+        let mut result = self;
+        ",
+        s.name,
+        op_info.trait_name,
+        trait_bounds,
+        generic_params,
+        op_info.fn_name,
+        if s.is_pub { "pub " } else { "" },
+        s.impl_generic_params(&[]),
+        scalar
+    ));
+    code.push_joined(
+        scalar_members_op(op_info, fields, &modes, "result"),
+        "\n        ",
+    );
+    code.push("\n        result\n    }\n}\n");
+    Ok(code)
+}
+
+fn generate_scalar_op_assign_trait_impl(
+    op_info: &OpInfo,
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+    scalar: &str,
+) -> Result<CodeBuilder, Diagnostics> {
+    let modes = field_modes(fields)?;
+    let generic_params = s.generic_args();
+    let trait_bounds = op_impl_generic_params(s, fields, &modes, |ty| {
+        format!(
+            "+core::ops::{}Assign<{}, {}>",
+            op_info.trait_name, ty, scalar
+        )
+    });
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+impl {0}Scalar{1}Assign{2}
+of core::ops::{1}Assign<{0}{3}, {5}> {{
+    fn {4}_assign(ref self: {0}{3}, rhs: {5}) {{
+        ",
+        s.name, op_info.trait_name, trait_bounds, generic_params, op_info.fn_name, scalar
+    ));
+    code.push_joined(
+        scalar_members_op(op_info, fields, &modes, "self"),
+        "\n        ",
+    );
+    code.push("\n    }\n}\n");
+    Ok(code)
 }
 
 /// Adds implementation for the `core::traits::Add` trait.
@@ -103,10 +335,9 @@ pub fn add(token_stream: TokenStream) -> ProcMacroResult {
         fn_name: "add".to_string(),
         operator: "+".to_string(),
     };
-
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_op_trait_impl(&op, &s)))
+    expand_struct_derive(token_stream, "Add", |s, fields| {
+        generate_op_trait_impl(&op, s, fields)
+    })
 }
 
 /// Adds implementation for the `core::traits::Sub` trait.
@@ -120,9 +351,9 @@ pub fn sub(token_stream: TokenStream) -> ProcMacroResult {
         fn_name: "sub".to_string(),
         operator: "-".to_string(),
     };
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_op_trait_impl(&op, &s)))
+    expand_struct_derive(token_stream, "Sub", |s, fields| {
+        generate_op_trait_impl(&op, s, fields)
+    })
 }
 
 /// Adds implementation for the `core::traits::Mul` trait.
@@ -136,9 +367,9 @@ pub fn mul(token_stream: TokenStream) -> ProcMacroResult {
         fn_name: "mul".to_string(),
         operator: "*".to_string(),
     };
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_op_trait_impl(&op, &s)))
+    expand_struct_derive(token_stream, "Mul", |s, fields| {
+        generate_op_trait_impl(&op, s, fields)
+    })
 }
 
 /// Adds implementation for the `core::traits::Div` trait.
@@ -152,9 +383,105 @@ pub fn div(token_stream: TokenStream) -> ProcMacroResult {
         fn_name: "div".to_string(),
         operator: "/".to_string(),
     };
-    let s = parse_struct_info(token_stream);
+    expand_struct_derive(token_stream, "Div", |s, fields| {
+        generate_op_trait_impl(&op, s, fields)
+    })
+}
+
+/// Adds implementation for the `core::traits::Rem` trait.
+///
+/// Allows you to use the `%` operator on a type. All members of
+/// the struct must already implement the `Rem` trait.
+#[derive_macro]
+pub fn rem(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "Rem".to_string(),
+        fn_name: "rem".to_string(),
+        operator: "%".to_string(),
+    };
+    expand_struct_derive(token_stream, "Rem", |s, fields| {
+        generate_op_trait_impl(&op, s, fields)
+    })
+}
+
+/// Adds implementation for the `core::traits::BitAnd` trait.
+///
+/// Allows you to use the `&` operator on a type. All members of
+/// the struct must already implement the `BitAnd` trait.
+#[derive_macro]
+pub fn bit_and(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "BitAnd".to_string(),
+        fn_name: "bitand".to_string(),
+        operator: "&".to_string(),
+    };
+    expand_struct_derive(token_stream, "BitAnd", |s, fields| {
+        generate_op_trait_impl(&op, s, fields)
+    })
+}
+
+/// Adds implementation for the `core::traits::BitOr` trait.
+///
+/// Allows you to use the `|` operator on a type. All members of
+/// the struct must already implement the `BitOr` trait.
+#[derive_macro]
+pub fn bit_or(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "BitOr".to_string(),
+        fn_name: "bitor".to_string(),
+        operator: "|".to_string(),
+    };
+    expand_struct_derive(token_stream, "BitOr", |s, fields| {
+        generate_op_trait_impl(&op, s, fields)
+    })
+}
+
+/// Adds implementation for the `core::traits::BitXor` trait.
+///
+/// Allows you to use the `^` operator on a type. All members of
+/// the struct must already implement the `BitXor` trait.
+#[derive_macro]
+pub fn bit_xor(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "BitXor".to_string(),
+        fn_name: "bitxor".to_string(),
+        operator: "^".to_string(),
+    };
+    expand_struct_derive(token_stream, "BitXor", |s, fields| {
+        generate_op_trait_impl(&op, s, fields)
+    })
+}
 
-    ProcMacroResult::new(TokenStream::new(generate_op_trait_impl(&op, &s)))
+/// Adds implementation for the `core::traits::Neg` trait.
+///
+/// Allows you to use the unary `-` operator on a type. All members of
+/// the struct must already implement the `Neg` trait.
+#[derive_macro]
+pub fn neg(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "Neg".to_string(),
+        fn_name: "neg".to_string(),
+        operator: "-".to_string(),
+    };
+    expand_struct_derive(token_stream, "Neg", |s, fields| {
+        generate_unary_op_trait_impl(&op, s, fields)
+    })
+}
+
+/// Adds implementation for the `core::traits::BitNot` trait.
+///
+/// Allows you to use the `~` operator on a type. All members of
+/// the struct must already implement the `BitNot` trait.
+#[derive_macro]
+pub fn bit_not(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "BitNot".to_string(),
+        fn_name: "bitnot".to_string(),
+        operator: "~".to_string(),
+    };
+    expand_struct_derive(token_stream, "BitNot", |s, fields| {
+        generate_unary_op_trait_impl(&op, s, fields)
+    })
 }
 
 /// Adds implementation for the `core::ops::AddAssign` trait.
@@ -168,9 +495,9 @@ fn add_assign(token_stream: TokenStream) -> ProcMacroResult {
         fn_name: "add".to_string(),
         operator: "+".to_string(),
     };
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_op_assign_trait_impl(&op, &s)))
+    expand_struct_derive(token_stream, "AddAssign", |s, fields| {
+        generate_op_assign_trait_impl(&op, s, fields)
+    })
 }
 
 /// Adds implementation for the `core::ops::SubAssign` trait.
@@ -184,9 +511,9 @@ fn sub_assign(token_stream: TokenStream) -> ProcMacroResult {
         fn_name: "sub".to_string(),
         operator: "-".to_string(),
     };
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_op_assign_trait_impl(&op, &s)))
+    expand_struct_derive(token_stream, "SubAssign", |s, fields| {
+        generate_op_assign_trait_impl(&op, s, fields)
+    })
 }
 
 /// Adds implementation for the `core::ops::MulAssign` trait.
@@ -200,9 +527,9 @@ fn mul_assign(token_stream: TokenStream) -> ProcMacroResult {
         fn_name: "mul".to_string(),
         operator: "*".to_string(),
     };
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_op_assign_trait_impl(&op, &s)))
+    expand_struct_derive(token_stream, "MulAssign", |s, fields| {
+        generate_op_assign_trait_impl(&op, s, fields)
+    })
 }
 
 /// Adds implementation for the `core::ops::DivAssign` trait.
@@ -216,7 +543,99 @@ fn div_assign(token_stream: TokenStream) -> ProcMacroResult {
         fn_name: "div".to_string(),
         operator: "/".to_string(),
     };
-    let s = parse_struct_info(token_stream);
+    expand_struct_derive(token_stream, "DivAssign", |s, fields| {
+        generate_op_assign_trait_impl(&op, s, fields)
+    })
+}
 
-    ProcMacroResult::new(TokenStream::new(generate_op_assign_trait_impl(&op, &s)))
+/// Adds implementation for the `core::ops::RemAssign` trait.
+///
+/// Allows you to use the `%=` operator on a type. All members of
+/// the struct must already implement the `RemAssign` trait.
+#[derive_macro]
+fn rem_assign(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "Rem".to_string(),
+        fn_name: "rem".to_string(),
+        operator: "%".to_string(),
+    };
+    expand_struct_derive(token_stream, "RemAssign", |s, fields| {
+        generate_op_assign_trait_impl(&op, s, fields)
+    })
+}
+
+/// Adds a `{Name}ScalarMul` trait with a `scalar_mul` method multiplying every member
+/// of the struct by the scalar of the `#[scalar(<type>)]` attribute.
+///
+/// Cairo's `Mul` trait only takes operands of the same type, so there is no `Mul<Point, u64>`
+/// impl and `p * 3_u64` doesn't compile: use `p.scalar_mul(3)`, or `p *= 3_u64` with the
+/// `MulAssign<Point, u64>` impl of the `ScalarMulAssign` derive.
+/// All members of the struct must implement `MulAssign` with the scalar as the right operand.
+#[derive_macro]
+pub fn scalar_mul(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "Mul".to_string(),
+        fn_name: "mul".to_string(),
+        operator: "*".to_string(),
+    };
+    expand_struct_derive(token_stream, "ScalarMul", |s, fields| {
+        let scalar = scalar_type(s, "ScalarMul")?;
+        generate_scalar_op_trait_impl(&op, s, fields, &scalar)
+    })
+}
+
+/// Adds a `{Name}ScalarDiv` trait with a `scalar_div` method dividing every member
+/// of the struct by the scalar of the `#[scalar(<type>)]` attribute.
+///
+/// Cairo's `Div` trait only takes operands of the same type, so there is no `Div<Point, u64>`
+/// impl and `p / 3_u64` doesn't compile: use `p.scalar_div(3)`, or `p /= 3_u64` with the
+/// `DivAssign<Point, u64>` impl of the `ScalarDivAssign` derive.
+/// All members of the struct must implement `DivAssign` with the scalar as the right operand.
+#[derive_macro]
+pub fn scalar_div(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "Div".to_string(),
+        fn_name: "div".to_string(),
+        operator: "/".to_string(),
+    };
+    expand_struct_derive(token_stream, "ScalarDiv", |s, fields| {
+        let scalar = scalar_type(s, "ScalarDiv")?;
+        generate_scalar_op_trait_impl(&op, s, fields, &scalar)
+    })
+}
+
+/// Adds implementation for the `core::ops::MulAssign` trait with the scalar of
+/// the `#[scalar(<type>)]` attribute as the right operand.
+///
+/// Allows you to use the `*=` operator with a scalar on a type. All members of
+/// the struct must implement `MulAssign` with the scalar as the right operand.
+#[derive_macro]
+fn scalar_mul_assign(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "Mul".to_string(),
+        fn_name: "mul".to_string(),
+        operator: "*".to_string(),
+    };
+    expand_struct_derive(token_stream, "ScalarMulAssign", |s, fields| {
+        let scalar = scalar_type(s, "ScalarMulAssign")?;
+        generate_scalar_op_assign_trait_impl(&op, s, fields, &scalar)
+    })
+}
+
+/// Adds implementation for the `core::ops::DivAssign` trait with the scalar of
+/// the `#[scalar(<type>)]` attribute as the right operand.
+///
+/// Allows you to use the `/=` operator with a scalar on a type. All members of
+/// the struct must implement `DivAssign` with the scalar as the right operand.
+#[derive_macro]
+fn scalar_div_assign(token_stream: TokenStream) -> ProcMacroResult {
+    let op = OpInfo {
+        trait_name: "Div".to_string(),
+        fn_name: "div".to_string(),
+        operator: "/".to_string(),
+    };
+    expand_struct_derive(token_stream, "ScalarDivAssign", |s, fields| {
+        let scalar = scalar_type(s, "ScalarDivAssign")?;
+        generate_scalar_op_assign_trait_impl(&op, s, fields, &scalar)
+    })
 }
@@ -0,0 +1,83 @@
+use crate::codegen::{spanned, CodeBuilder};
+use crate::parse::{expand_struct_derive, FieldInfo, ItemInfo};
+use cairo_lang_macro::{derive_macro, ProcMacroResult, TokenStream};
+
+fn generate_one_trait_impl(s: &ItemInfo, fields: &[FieldInfo]) -> CodeBuilder {
+    let generic_params = s.generic_args();
+
+    let bounds = s
+        .generic_field_types(fields)
+        .iter()
+        .flat_map(|ty| {
+            vec![
+                format!("+core::num::traits::One<{}>", ty),
+                format!("+core::traits::Drop<{}>", ty),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let trait_bounds = s.impl_generic_params(&bounds);
+
+    let one_fn = fields.iter().map(|field| {
+        spanned(
+            format!("{}: core::num::traits::One::one()", field.name),
+            &field.span,
+        )
+    });
+
+    let is_one_fn = fields
+        .iter()
+        .map(|field| spanned(format!("self.{}.is_one()", field.name), &field.span));
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+impl {0}OneImpl{1}
+of core::num::traits::One<{0}{2}> {{
+    fn one() -> {0}{2} {{
+        {0} {{ ",
+        s.name, trait_bounds, generic_params
+    ));
+    code.push_joined(one_fn, ", ");
+    code.push(format!(
+        " }}
+    }}
+
+    fn is_one(self: @{0}{1}) -> bool {{
+        ",
+        s.name, generic_params
+    ));
+    code.push_joined(is_one_fn, " && ");
+    code.push(format!(
+        "
+    }}
+
+    fn is_non_one(self: @{0}{1}) -> bool {{
+        !self.is_one()
+    }}
+}}\n",
+        s.name, generic_params
+    ));
+    code
+}
+
+/// Adds implementation of the `core::num::traits::One` trait.
+///
+/// All members of the struct must already implement the `One` trait.
+///
+/// ```
+/// #[derive(One, PartialEq, Debug)]
+/// struct Point {
+///     x: u64,
+///     y: u64,
+/// }
+///
+/// assert_eq!(Point { x: 1, y: 1 }, One::one());
+/// assert!(Point { x: 1, y: 1 }.is_one());
+/// assert!(Point { x: 1, y: 0 }.is_non_one());
+/// ```
+#[derive_macro]
+pub fn one(token_stream: TokenStream) -> ProcMacroResult {
+    expand_struct_derive(token_stream, "One", |s, fields| {
+        Ok(generate_one_trait_impl(s, fields))
+    })
+}
@@ -1,103 +1,363 @@
-use cairo_lang_macro::TokenStream;
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TextSpan, TokenStream};
 use cairo_lang_parser::utils::SimpleParserDatabase;
-use cairo_lang_syntax::node::kind::SyntaxKind::{
-    ExprPath, Member, OptionWrappedGenericParamListEmpty, TerminalStruct, TokenIdentifier,
-    WrappedGenericParamList,
+use cairo_lang_syntax::node::ast::{
+    self, GenericParam, ModuleItem, OptionArgListParenthesized, OptionTypeClause,
+    OptionWrappedGenericParamList,
 };
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
+
+use crate::codegen::CodeBuilder;
+use crate::token_tree_parser::{MacroArg, TokenTreeParser};
+
+/// An attribute attached to an item, a field or a variant,
+/// e.g. `#[json(rename = "id")]` has the name `json` and the single named arg `rename = "id"`.
+pub(crate) struct AttributeInfo {
+    pub(crate) name: String,
+    pub(crate) args: Vec<MacroArg>,
+    pub(crate) span: TextSpan,
+}
+
+impl AttributeInfo {
+    /// Error on this attribute.
+    pub(crate) fn error(&self, message: impl ToString) -> Diagnostic {
+        Diagnostic::span_error(self.span.clone(), message)
+    }
+}
+
+/// The first attribute called `name` among `attributes`.
+pub(crate) fn find_attribute<'a>(
+    attributes: &'a [AttributeInfo],
+    name: &str,
+) -> Option<&'a AttributeInfo> {
+    attributes.iter().find(|attr| attr.name == name)
+}
 
 pub(crate) struct FieldInfo {
     pub(crate) name: String,
     pub(crate) field_type: String,
+    pub(crate) attributes: Vec<AttributeInfo>,
+    /// Span of the whole member declaration in the user's code.
+    pub(crate) span: TextSpan,
+    pub(crate) type_span: TextSpan,
 }
 
-pub(crate) struct StructInfo {
+pub(crate) struct VariantInfo {
     pub(crate) name: String,
-    pub(crate) generic_params: Option<Vec<String>>,
-    pub(crate) fields: Vec<FieldInfo>,
-    // Keep backward compatibility
-    pub(crate) members: Vec<String>,
+    /// Type of the variant payload, `None` for unit variants.
+    pub(crate) payload: Option<String>,
+    pub(crate) attributes: Vec<AttributeInfo>,
+    pub(crate) span: TextSpan,
 }
 
-pub(crate) fn parse_struct_info(token_stream: TokenStream) -> StructInfo {
-    let db = SimpleParserDatabase::default();
-    let (parsed, _diag) = db.parse_virtual_with_diagnostics(token_stream);
-    let mut nodes = parsed.descendants(&db);
-
-    // find struct name - the next TokenIdentifier after TeminalStruct
-    let mut struct_name = String::new();
-    for node in nodes.by_ref() {
-        if node.kind(&db) == TerminalStruct {
-            struct_name = nodes
-                .find(|node| node.kind(&db) == TokenIdentifier)
-                .unwrap()
-                .get_text(&db);
-            break;
+pub(crate) enum GenericParamInfo {
+    /// A type parameter, e.g. `T`.
+    Type { name: String },
+    /// A const parameter, e.g. `const N: usize`.
+    Const { name: String, ty: String },
+    /// An impl parameter (named, anonymous or negative), kept verbatim, e.g. `+Drop<T>`.
+    Impl { text: String },
+}
+
+pub(crate) enum ItemKind {
+    Struct(Vec<FieldInfo>),
+    Enum(Vec<VariantInfo>),
+}
+
+pub(crate) struct ItemInfo {
+    pub(crate) name: String,
+    /// Span of the item name, used for diagnostics concerning the whole item.
+    pub(crate) span: TextSpan,
+    pub(crate) attributes: Vec<AttributeInfo>,
+    pub(crate) is_pub: bool,
+    pub(crate) generic_params: Vec<GenericParamInfo>,
+    pub(crate) kind: ItemKind,
+}
+
+impl ItemInfo {
+    /// Generic arguments naming the item type, e.g. `<T, N>` for `Buf<T, const N: usize, +Drop<T>>`.
+    ///
+    /// Impl parameters are left out, they're inferred at the use site.
+    pub(crate) fn generic_args(&self) -> String {
+        let args = self
+            .generic_params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParamInfo::Type { name } | GenericParamInfo::Const { name, .. } => {
+                    Some(name.as_str())
+                }
+                GenericParamInfo::Impl { .. } => None,
+            })
+            .collect::<Vec<_>>();
+
+        if args.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", args.join(", "))
         }
     }
 
-    // collect generic params or skip if there aren't any
-    let mut generic_params: Option<Vec<String>> = None;
-    for node in nodes.by_ref() {
-        match node.kind(&db) {
-            WrappedGenericParamList => {
-                let params = node
-                    .descendants(&db)
-                    .filter(|node| node.kind(&db) == TokenIdentifier)
-                    .map(|node| node.get_text(&db))
-                    .collect();
-                generic_params = Some(params);
-                break;
+    /// Generic parameters of an impl for the item: the parameters of the item kept verbatim,
+    /// followed by the `bounds` the item doesn't already declare.
+    pub(crate) fn impl_generic_params(&self, bounds: &[String]) -> String {
+        let params = self
+            .generic_params
+            .iter()
+            .map(|param| match param {
+                GenericParamInfo::Type { name } => name.clone(),
+                GenericParamInfo::Const { name, ty } => format!("const {name}: {ty}"),
+                GenericParamInfo::Impl { text } => text.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut declared = self
+            .generic_params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParamInfo::Impl { text } => Some(bound_key(text)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut new_bounds = Vec::new();
+        for bound in bounds {
+            let key = bound_key(bound);
+            if !declared.contains(&key) {
+                declared.push(key);
+                new_bounds.push(bound.as_str());
             }
-            OptionWrappedGenericParamListEmpty => {
-                break;
+        }
+
+        match (params.is_empty(), new_bounds.is_empty()) {
+            (true, true) => String::new(),
+            (false, true) => format!("<{}>", params.join(", ")),
+            (true, false) => format!("<{}>", new_bounds.join(",\n")),
+            (false, false) => format!("<{},\n{}>", params.join(", "), new_bounds.join(",\n")),
+        }
+    }
+
+    /// Distinct types of the `fields` that depend on a generic parameter of the item,
+    /// e.g. `T` and `Array<T>` but not `u64`. These are the types a derived impl has to bound.
+    pub(crate) fn generic_field_types<'a>(&self, fields: &'a [FieldInfo]) -> Vec<&'a str> {
+        let mut types = Vec::new();
+        for field in fields {
+            let ty = field.field_type.as_str();
+            if self.is_generic_type(ty) && !types.contains(&ty) {
+                types.push(ty);
             }
-            _ => {}
+        }
+        types
+    }
+
+    /// Whether `ty` depends on a generic parameter of the item.
+    pub(crate) fn is_generic_type(&self, ty: &str) -> bool {
+        let params = self
+            .generic_params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParamInfo::Type { name } | GenericParamInfo::Const { name, .. } => {
+                    Some(name.as_str())
+                }
+                GenericParamInfo::Impl { .. } => None,
+            })
+            .collect::<Vec<_>>();
+
+        ty.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .any(|ident| params.contains(&ident))
+    }
+
+    /// Fields of the item if it is a struct.
+    pub(crate) fn struct_fields(&self) -> Option<&[FieldInfo]> {
+        match &self.kind {
+            ItemKind::Struct(fields) => Some(fields),
+            ItemKind::Enum(_) => None,
+        }
+    }
+
+    /// Variants of the item if it is an enum.
+    pub(crate) fn enum_variants(&self) -> Option<&[VariantInfo]> {
+        match &self.kind {
+            ItemKind::Struct(_) => None,
+            ItemKind::Enum(variants) => Some(variants),
         }
     }
+}
+
+/// Normalizes an impl parameter to compare it with other bounds,
+/// e.g. `impl TDrop: core::traits::Drop<T>` and `+Drop< T >` both become `Drop<T>`.
+fn bound_key(bound: &str) -> String {
+    let bound = bound.split_whitespace().collect::<String>();
+    let bound = match bound.strip_prefix("impl") {
+        Some(named) => named
+            .split_once(':')
+            .map_or(named, |(_, trait_path)| trait_path),
+        None => bound.strip_prefix('+').unwrap_or(&bound),
+    };
+    let trait_end = bound.find('<').unwrap_or(bound.len());
+    let trait_start = bound[..trait_end].rfind("::").map_or(0, |idx| idx + 2);
+    bound[trait_start..].to_string()
+}
+
+/// Parses the item a derive or an attribute macro was applied to.
+///
+/// Reports an error if the token stream doesn't contain a struct or an enum.
+pub(crate) fn parse_item(token_stream: &TokenStream) -> Result<ItemInfo, Diagnostics> {
+    let db = SimpleParserDatabase::default();
+    let (parsed, _diag) = db.parse_token_stream(token_stream);
+    let file = ast::SyntaxFile::from_syntax_node(&db, parsed);
+
+    for item in file.items(&db).elements(&db) {
+        match item {
+            ModuleItem::Struct(item) => {
+                let mut fields = Vec::new();
+                for member in item.members(&db).elements(&db) {
+                    let ty = member.type_clause(&db).ty(&db);
+                    fields.push(FieldInfo {
+                        name: member.name(&db).text(&db).to_string(),
+                        field_type: node_text(&db, &ty),
+                        attributes: parse_attributes(&db, member.attributes(&db))?,
+                        span: node_span(&db, &member),
+                        type_span: node_span(&db, &ty),
+                    });
+                }
 
-    // collect struct members with their types
-    let mut fields = Vec::new();
-    let mut members = Vec::new(); // Keep for backward compatibility
-
-    // Re-parse from the beginning to get member information
-    let nodes = parsed.descendants(&db);
-
-    for node in nodes {
-        if node.kind(&db) == Member {
-            let member_descendants: Vec<_> = node.descendants(&db).collect();
-
-            // Find field name (first TokenIdentifier)
-            let field_name = member_descendants
-                .iter()
-                .find(|n| n.kind(&db) == TokenIdentifier)
-                .map(|n| n.get_text(&db))
-                .unwrap_or_default();
-
-            // Extract type information by looking for ExprPath node after colon
-            let field_type = member_descendants
-                .iter()
-                .find(|n| n.kind(&db) == ExprPath)
-                .map(|n| n.get_text(&db))
-                .unwrap_or_default();
-
-            if !field_name.is_empty() {
-                fields.push(FieldInfo {
-                    name: field_name.clone(),
-                    field_type: if field_type.is_empty() {
-                        "ByteArray".to_string()
-                    } else {
-                        field_type
-                    },
+                return Ok(ItemInfo {
+                    name: item.name(&db).text(&db).to_string(),
+                    span: node_span(&db, &item.name(&db)),
+                    attributes: parse_attributes(&db, item.attributes(&db))?,
+                    is_pub: is_pub(item.visibility(&db)),
+                    generic_params: parse_generic_params(&db, item.generic_params(&db)),
+                    kind: ItemKind::Struct(fields),
                 });
-                members.push(field_name); // Backward compatibility
             }
+            ModuleItem::Enum(item) => {
+                let mut variants = Vec::new();
+                for variant in item.variants(&db).elements(&db) {
+                    let payload = match variant.type_clause(&db) {
+                        OptionTypeClause::Empty(_) => None,
+                        OptionTypeClause::TypeClause(clause) => {
+                            Some(node_text(&db, &clause.ty(&db)))
+                        }
+                    };
+                    variants.push(VariantInfo {
+                        name: variant.name(&db).text(&db).to_string(),
+                        payload,
+                        attributes: parse_attributes(&db, variant.attributes(&db))?,
+                        span: node_span(&db, &variant),
+                    });
+                }
+
+                return Ok(ItemInfo {
+                    name: item.name(&db).text(&db).to_string(),
+                    span: node_span(&db, &item.name(&db)),
+                    attributes: parse_attributes(&db, item.attributes(&db))?,
+                    is_pub: is_pub(item.visibility(&db)),
+                    generic_params: parse_generic_params(&db, item.generic_params(&db)),
+                    kind: ItemKind::Enum(variants),
+                });
+            }
+            _ => {}
         }
     }
 
-    StructInfo {
-        name: struct_name,
-        generic_params,
-        fields,
-        members,
+    Err(Diagnostic::error("expected a struct or an enum").into())
+}
+
+/// Parses the item a derive was applied to and expands it with `generate`.
+pub(crate) fn expand_derive(
+    token_stream: TokenStream,
+    generate: impl FnOnce(&ItemInfo) -> Result<CodeBuilder, Diagnostics>,
+) -> ProcMacroResult {
+    let result = parse_item(&token_stream).and_then(|item| generate(&item));
+    match result {
+        Ok(code) => ProcMacroResult::new(code.build()),
+        Err(diagnostics) => error_result(diagnostics),
+    }
+}
+
+/// Parses the struct a derive was applied to and expands it with `generate`.
+///
+/// Reports an error on the item name if the derive was applied to anything but a struct.
+pub(crate) fn expand_struct_derive(
+    token_stream: TokenStream,
+    derive_name: &str,
+    generate: impl FnOnce(&ItemInfo, &[FieldInfo]) -> Result<CodeBuilder, Diagnostics>,
+) -> ProcMacroResult {
+    expand_derive(token_stream, |item| {
+        let Some(fields) = item.struct_fields() else {
+            return Err(Diagnostic::span_error(
+                item.span.clone(),
+                format!("`{derive_name}` can only be derived on structs"),
+            )
+            .into());
+        };
+        generate(item, fields)
+    })
+}
+
+/// Result of a macro that failed to expand, carrying only its diagnostics.
+pub(crate) fn error_result(diagnostics: Diagnostics) -> ProcMacroResult {
+    ProcMacroResult::new(TokenStream::empty()).with_diagnostics(diagnostics)
+}
+
+/// Span of a node in the user's code, without the surrounding whitespace and comments.
+pub(crate) fn node_span(db: &dyn SyntaxGroup, node: &impl TypedSyntaxNode) -> TextSpan {
+    let span = node.as_syntax_node().span_without_trivia(db);
+    TextSpan::new(span.start.as_u32(), span.end.as_u32())
+}
+
+fn node_text(db: &dyn SyntaxGroup, node: &impl TypedSyntaxNode) -> String {
+    node.as_syntax_node().get_text_without_trivia(db)
+}
+
+fn is_pub(visibility: ast::Visibility) -> bool {
+    matches!(visibility, ast::Visibility::Pub(_))
+}
+
+fn parse_attributes(
+    db: &dyn SyntaxGroup,
+    attributes: ast::AttributeList,
+) -> Result<Vec<AttributeInfo>, Diagnostics> {
+    let mut result = Vec::new();
+    for attr in attributes.elements(db) {
+        let args = match attr.arguments(db) {
+            OptionArgListParenthesized::Empty(_) => Vec::new(),
+            OptionArgListParenthesized::ArgListParenthesized(args) => {
+                TokenTreeParser::parse_attribute_args(&args, db)?
+            }
+        };
+        result.push(AttributeInfo {
+            name: node_text(db, &attr.attr(db)),
+            args,
+            span: node_span(db, &attr),
+        });
+    }
+    Ok(result)
+}
+
+fn parse_generic_params(
+    db: &dyn SyntaxGroup,
+    params: OptionWrappedGenericParamList,
+) -> Vec<GenericParamInfo> {
+    let OptionWrappedGenericParamList::WrappedGenericParamList(params) = params else {
+        return Vec::new();
+    };
+
+    let mut result = Vec::new();
+    for param in params.generic_params(db).elements(db) {
+        result.push(match &param {
+            GenericParam::Type(param) => GenericParamInfo::Type {
+                name: param.name(db).text(db).to_string(),
+            },
+            GenericParam::Const(param) => GenericParamInfo::Const {
+                name: param.name(db).text(db).to_string(),
+                ty: node_text(db, &param.ty(db)),
+            },
+            GenericParam::ImplNamed(_)
+            | GenericParam::ImplAnonymous(_)
+            | GenericParam::NegativeImpl(_) => GenericParamInfo::Impl {
+                text: node_text(db, &param),
+            },
+        });
     }
+    result
 }
@@ -0,0 +1,300 @@
+use cairo_lang_macro::{
+    derive_macro, executable_attribute, Diagnostics, ProcMacroResult, TokenStream,
+};
+
+use crate::codegen::{spanned, CodeBuilder};
+use crate::parse::{expand_derive, find_attribute, FieldInfo, ItemInfo, ItemKind, VariantInfo};
+
+// Declares the `#[order]` helper attribute so that the compiler accepts it on members.
+executable_attribute!("order");
+
+/// A function of the `PartialOrd` trait.
+struct Comparison {
+    fn_name: &'static str,
+    /// Strict operator comparing two different values.
+    operator: &'static str,
+    /// Result for two equal values.
+    or_equal: bool,
+}
+
+const COMPARISONS: [Comparison; 4] = [
+    Comparison {
+        fn_name: "lt",
+        operator: "<",
+        or_equal: false,
+    },
+    Comparison {
+        fn_name: "le",
+        operator: "<",
+        or_equal: true,
+    },
+    Comparison {
+        fn_name: "gt",
+        operator: ">",
+        or_equal: false,
+    },
+    Comparison {
+        fn_name: "ge",
+        operator: ">",
+        or_equal: true,
+    },
+];
+
+/// A member taking part in the ordering of a struct.
+struct OrderField<'a> {
+    field: &'a FieldInfo,
+    reverse: bool,
+}
+
+/// Members the struct is ordered by, in declaration order: the `#[order(key)]` members if
+/// any, all members otherwise. Reports every invalid `#[order(...)]` attribute, including a
+/// `reverse` that would be ignored because the member isn't one of the keys.
+fn order_fields(fields: &[FieldInfo]) -> Result<Vec<OrderField<'_>>, Diagnostics> {
+    let mut errors = Vec::new();
+    let mut has_keys = false;
+    let mut order_fields = Vec::new();
+    for field in fields {
+        let mut key = false;
+        let mut reverse = None;
+        if let Some(attr) = find_attribute(&field.attributes, "order") {
+            if attr.args.is_empty() {
+                errors.push(attr.error("expected `#[order(key)]` or `#[order(reverse)]`"));
+            }
+            for arg in &attr.args {
+                match (&arg.name, arg.value.as_str()) {
+                    (None, "key") => key = true,
+                    (None, "reverse") => reverse = Some(arg),
+                    _ => errors.push(arg.error("expected `key` or `reverse`")),
+                }
+            }
+        }
+        has_keys |= key;
+        order_fields.push((field, key, reverse));
+    }
+
+    if has_keys {
+        errors.extend(
+            order_fields
+                .iter()
+                .filter_map(|(_, key, reverse)| reverse.filter(|_| !key))
+                .map(|reverse| {
+                    reverse
+                        .error("`reverse` has no effect without `key` when other members are keys")
+                }),
+        );
+    }
+
+    if !errors.is_empty() {
+        return Err(Diagnostics::new(errors));
+    }
+    Ok(order_fields
+        .into_iter()
+        .filter(|(_, key, _)| !has_keys || *key)
+        .map(|(field, _, reverse)| OrderField {
+            field,
+            reverse: reverse.is_some(),
+        })
+        .collect())
+}
+
+fn generate_struct_partial_ord_impl(
+    s: &ItemInfo,
+    fields: &[FieldInfo],
+) -> Result<CodeBuilder, Diagnostics> {
+    let order_fields = order_fields(fields)?;
+    let generic_params = s.generic_args();
+
+    let mut bounds = Vec::new();
+    for field in fields {
+        let is_key = order_fields
+            .iter()
+            .any(|order_field| std::ptr::eq(order_field.field, field));
+        for ty in s.generic_field_types(std::slice::from_ref(field)) {
+            if is_key {
+                bounds.push(format!("+core::traits::PartialOrd<{}>", ty));
+                bounds.push(format!("+core::traits::PartialEq<{}>", ty));
+            }
+            bounds.push(format!("+core::traits::Drop<{}>", ty));
+        }
+    }
+    let trait_bounds = s.impl_generic_params(&bounds);
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+impl {0}PartialOrdImpl{1}
+of core::traits::PartialOrd<{0}{2}> {{",
+        s.name, trait_bounds, generic_params
+    ));
+    for comparison in &COMPARISONS {
+        // The first member that differs decides, the values are equal if none does.
+        let members_cmp = order_fields.iter().map(|order_field| {
+            let operator = match (comparison.operator, order_field.reverse) {
+                (operator, false) => operator,
+                ("<", true) => ">",
+                (_, true) => "<",
+            };
+            spanned(
+                format!(
+                    "if lhs.{0} != rhs.{0} {{\n            return lhs.{0} {1} rhs.{0};\n        }}",
+                    order_field.field.name, operator
+                ),
+                &order_field.field.span,
+            )
+        });
+
+        code.push(format!(
+            "
+    fn {1}(lhs: {0}{2}, rhs: {0}{2}) -> bool {{
+        ",
+            s.name, comparison.fn_name, generic_params
+        ));
+        code.push_joined(members_cmp, "\n        ");
+        code.push(format!("\n        {}\n    }}\n", comparison.or_equal));
+    }
+    code.push("}\n");
+    Ok(code)
+}
+
+fn generate_enum_partial_ord_impl(s: &ItemInfo, variants: &[VariantInfo]) -> CodeBuilder {
+    let generic_params = s.generic_args();
+
+    let bounds = variants
+        .iter()
+        .filter_map(|variant| variant.payload.as_deref())
+        .filter(|ty| s.is_generic_type(ty))
+        .flat_map(|ty| {
+            vec![
+                format!("+core::traits::PartialOrd<{}>", ty),
+                format!("+core::traits::PartialEq<{}>", ty),
+                format!("+core::traits::Drop<{}>", ty),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let trait_bounds = s.impl_generic_params(&bounds);
+
+    let pattern = |variant: &VariantInfo, binding: &str| match variant.payload {
+        Some(_) => format!("{}::{}({})", s.name, variant.name, binding),
+        None => format!("{}::{}", s.name, variant.name),
+    };
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+impl {0}PartialOrdImpl{1}
+of core::traits::PartialOrd<{0}{2}> {{",
+        s.name, trait_bounds, generic_params
+    ));
+    for comparison in &COMPARISONS {
+        // Variants are ordered by their index, the payloads of the same variant by their value.
+        let lhs_arms = variants.iter().enumerate().map(|(lhs_index, lhs)| {
+            let mut arm = CodeBuilder::default();
+            arm.push(format!(
+                "{} => match rhs {{\n                ",
+                pattern(lhs, "lhs_value")
+            ));
+            let rhs_arms = variants.iter().enumerate().map(|(rhs_index, rhs)| {
+                let result = if lhs_index != rhs_index {
+                    match comparison.operator {
+                        "<" => lhs_index < rhs_index,
+                        _ => lhs_index > rhs_index,
+                    }
+                    .to_string()
+                } else if lhs.payload.is_some() {
+                    let or_equal = if comparison.or_equal { "=" } else { "" };
+                    format!("lhs_value {}{} rhs_value", comparison.operator, or_equal)
+                } else {
+                    comparison.or_equal.to_string()
+                };
+                let binding = if lhs_index == rhs_index {
+                    "rhs_value"
+                } else {
+                    "_"
+                };
+                spanned(
+                    format!("{} => {},", pattern(rhs, binding), result),
+                    &rhs.span,
+                )
+            });
+            arm.push_joined(rhs_arms, "\n                ");
+            arm.push("\n            },");
+            arm
+        });
+
+        code.push(format!(
+            "
+    fn {1}(lhs: {0}{2}, rhs: {0}{2}) -> bool {{
+        match lhs {{
+            ",
+            s.name, comparison.fn_name, generic_params
+        ));
+        code.push_joined(lhs_arms, "\n            ");
+        code.push("\n        }\n    }\n");
+    }
+    code.push("}\n");
+    code
+}
+
+/// Adds implementation of the `core::traits::PartialOrd` trait.
+///
+/// Structs are compared member by member in declaration order, the first member that
+/// differs decides. The `#[order(key)]` member attribute restricts the comparison to the
+/// marked members, and `#[order(reverse)]` reverses the order of a member. All compared
+/// members must already implement the `PartialOrd` and `PartialEq` traits.
+///
+/// Enums are ordered by the index of their variants, and values of the same variant by
+/// their payload, which must already implement the `PartialOrd` trait.
+///
+/// ```
+/// #[derive(PartialOrd, PartialEq, Drop)]
+/// struct Order {
+///     #[order(key, reverse)]
+///     price: u64,
+///     #[order(key)]
+///     time: u64,
+///     id: felt252,
+/// }
+///
+/// let expensive = Order { price: 10, time: 2, id: 1 };
+/// let cheap = Order { price: 5, time: 1, id: 2 };
+/// assert!(expensive < cheap);
+/// ```
+#[derive_macro]
+pub fn partial_ord(token_stream: TokenStream) -> ProcMacroResult {
+    expand_derive(token_stream, |s| match &s.kind {
+        ItemKind::Struct(fields) => generate_struct_partial_ord_impl(s, fields),
+        ItemKind::Enum(variants) => Ok(generate_enum_partial_ord_impl(s, variants)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_item;
+    use cairo_lang_macro::{TextSpan, Token, TokenTree};
+
+    fn order_errors(code: &str) -> Vec<String> {
+        let token_stream = TokenStream::new(vec![TokenTree::Ident(Token::new(
+            code,
+            TextSpan::call_site(),
+        ))]);
+        let item = parse_item(&token_stream).unwrap();
+        match order_fields(item.struct_fields().unwrap()) {
+            Ok(_) => vec![],
+            Err(diagnostics) => diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.message().to_string())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_reverse_without_key() {
+        assert_eq!(
+            order_errors("struct S { #[order(key)] a: u8, #[order(reverse)] b: u8 }"),
+            vec!["`reverse` has no effect without `key` when other members are keys"]
+        );
+        assert!(order_errors("struct S { #[order(key, reverse)] a: u8, b: u8 }").is_empty());
+        assert!(order_errors("struct S { a: u8, #[order(reverse)] b: u8 }").is_empty());
+    }
+}
@@ -1,13 +1,15 @@
+use std::fmt::Display;
+
 use bigdecimal::num_bigint::BigInt;
-use bigdecimal::{num_traits::pow, BigDecimal};
+use bigdecimal::num_traits::{pow, ToPrimitive};
 
-use crate::token_tree_parser::TokenTreeParser;
-use cairo_lang_filesystem::ids::{FileKind, FileLongId, VirtualFile};
-use cairo_lang_macro::{inline_macro, Diagnostic, ProcMacroResult, TokenStream};
-use cairo_lang_parser::db::ParserGroup;
-use cairo_lang_parser::utils::SimpleParserDatabase;
-use cairo_lang_syntax::node::ast::{Expr, ExprInlineMacro};
-use cairo_lang_utils::{Intern, Upcast};
+use crate::codegen::spanned;
+use crate::int_types::IntType;
+use crate::parse::error_result;
+use crate::token_tree_parser::{
+    parse_integer_literal, token_stream_span, MacroArg, TokenTreeParser,
+};
+use cairo_lang_macro::{inline_macro, Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
 
 /// Compile-time power function.
 ///
@@ -19,72 +21,236 @@ use cairo_lang_utils::{Intern, Upcast};
 /// ```
 ///
 /// Takes two arguments, `x, y`, calculates the value of `x` raised to the power of `y`.
+///
+/// An optional third argument gives the integer type of the result, e.g. `pow!(2, 80, u128)`.
+/// The result is then checked to fit in the type and emitted as a typed literal.
 #[inline_macro]
 pub fn pow(token_stream: TokenStream) -> ProcMacroResult {
-    let db = SimpleParserDatabase::default();
-    // Get the ExprInlineMacro object so we can use the helper functions.
-    let mac = parse_inline_macro(token_stream, &db);
-    // Get the arguments of the macro. In Cairo 2.12.0, arguments are now TokenTreeNode
-    let token_tree = mac.arguments(db.upcast());
-
-    // Parse the token tree to extract arguments - use the proper TokenTreeParser
-    let macro_args = TokenTreeParser::parse_simple_string(&token_tree, &db);
-
-    if macro_args.len() != 2 {
-        return ProcMacroResult::new(TokenStream::empty())
-            .with_diagnostics(Diagnostic::error("Invalid number of arguments").into());
+    let macro_args = match TokenTreeParser::parse_inline_macro_args("pow", &token_stream) {
+        Ok(args) => args,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
+
+    if !(2..=3).contains(&macro_args.len()) {
+        return error_result(
+            Diagnostic::span_error(
+                token_stream_span(&token_stream),
+                format!(
+                    "Invalid number of arguments, expected 2 or 3 but got {}",
+                    macro_args.len()
+                ),
+            )
+            .into(),
+        );
     }
 
-    let base = match macro_args[0].parse::<BigInt>() {
-        Ok(val) => val,
-        Err(_) => {
-            return ProcMacroResult::new(TokenStream::empty())
-                .with_diagnostics(Diagnostic::error("Invalid base value").into())
-        }
+    if let Some(arg) = macro_args.iter().find(|arg| arg.name.is_some()) {
+        return error_result(arg.error("`pow!` doesn't take named arguments").into());
     }
-    .into();
 
-    let exp = match macro_args[1].parse::<BigInt>() {
-        Ok(val) => val,
-        Err(_) => {
-            return ProcMacroResult::new(TokenStream::empty())
-                .with_diagnostics(Diagnostic::error("Invalid exponent value").into())
+    let base = match macro_args[0].as_integer() {
+        Some(val) => val,
+        None => {
+            return error_result(
+                macro_args[0]
+                    .error(format!(
+                        "Invalid base value `{}`, expected an integer",
+                        macro_args[0].value
+                    ))
+                    .into(),
+            )
+        }
+    };
+
+    let exp = match macro_args[1].as_integer().and_then(|exp| exp.to_usize()) {
+        Some(val) => val,
+        None => {
+            return error_result(
+                macro_args[1]
+                    .error(format!(
+                        "Invalid exponent value `{}`, expected a non-negative integer",
+                        macro_args[1].value
+                    ))
+                    .into(),
+            )
+        }
+    };
+
+    let ty = match macro_args.get(2).map(IntType::from_arg).transpose() {
+        Ok(ty) => ty,
+        Err(diagnostic) => return error_result(diagnostic.into()),
+    };
+
+    let out_of_range = |ty: &IntType, value: &dyn Display| {
+        error_result(
+            Diagnostic::span_error(
+                token_stream_span(&token_stream),
+                format!(
+                    "`pow!({}, {})` = {}",
+                    macro_args[0].value,
+                    macro_args[1].value,
+                    ty.out_of_range_message(value)
+                ),
+            )
+            .into(),
+        )
+    };
+
+    // For |base| >= 2, |base|^exp >= 2^exp leaves the range of the type as soon as `exp` exceeds the
+    // bit width of its maximum, so reject it before computing a potentially huge power.
+    if let Some(ty) = &ty {
+        if base.magnitude().bits() > 1 && exp as u64 > ty.max.bits() {
+            return out_of_range(ty, &format_args!("{}^{}", base, exp));
         }
     }
-    .try_into();
-    let exp = if let Ok(exponent) = exp {
-        exponent
-    } else {
-        return ProcMacroResult::new(TokenStream::empty())
-            .with_diagnostics(Diagnostic::error("Invalid exponent value").into());
+
+    let result: BigInt = pow(base, exp);
+
+    let literal = match ty {
+        Some(ty) if !ty.contains(&result) => return out_of_range(&ty, &result),
+        Some(ty) => ty.literal(&result),
+        None => result.to_string(),
     };
 
-    let result: BigDecimal = pow(base, exp);
+    ProcMacroResult::new(spanned(literal, &token_stream_span(&token_stream)).build())
+}
+
+/// Largest number of entries of a table generated by `pow_table!`.
+const MAX_TABLE_LEN: usize = 4096;
 
-    ProcMacroResult::new(TokenStream::new(result.to_string()))
+/// Parses a range of exponents, `start..end` or `start..=end`, into its inclusive bounds.
+fn parse_exponent_range(arg: &MacroArg) -> Result<(usize, usize), Diagnostic> {
+    let invalid = || {
+        arg.error(format!(
+            "Invalid range `{}`, expected non-negative integer bounds such as `0..256`",
+            arg.value
+        ))
+    };
+    let (start, end, inclusive) = match arg.value.split_once("..") {
+        Some((start, end)) => match end.strip_prefix('=') {
+            Some(end) => (start, end, true),
+            None => (start, end, false),
+        },
+        None => return Err(invalid()),
+    };
+    let bound = |bound: &str| parse_integer_literal(bound).and_then(|bound| bound.to_usize());
+    let (Some(start), Some(end)) = (bound(start), bound(end)) else {
+        return Err(invalid());
+    };
+
+    let last = if inclusive {
+        Some(end)
+    } else {
+        end.checked_sub(1)
+    };
+    match last {
+        Some(last) if start <= last && last - start < MAX_TABLE_LEN => Ok((start, last)),
+        Some(last) if start <= last => Err(arg.error(format!(
+            "The range `{}` has more than {MAX_TABLE_LEN} exponents",
+            arg.value
+        ))),
+        _ => Err(arg.error(format!("The range `{}` is empty", arg.value))),
+    }
 }
 
-/// Return an [`ExprInlineMacro`] from the text received. The expected text is the macro arguments.
-/// For example the initial macro text was `pow!(10, 3)`, the text in the token stream is only `(10, 3)`
-fn parse_inline_macro(token_stream: impl ToString, db: &SimpleParserDatabase) -> ExprInlineMacro {
-    // Create a virtual file that will be parsed.
-    let file = FileLongId::Virtual(VirtualFile {
-        parent: None,
-        name: "parser_input".into(),
-        content: format!("pow!{}", token_stream.to_string()).into(), // easiest workaround after change
-        code_mappings: [].into(),
-        kind: FileKind::Expr, // this part is different than db.parse_virtual
-        original_item_removed: false,
-    })
-    .intern(db);
-
-    // Could fail if there was a parsing error but it shouldn't happen as the file has already
-    // been parsed once to reach this macro.
-    let node = db.file_expr_syntax(file).unwrap();
-
-    let Expr::InlineMacro(inline_macro) = node else {
-        panic!() // should not happen
+/// Compile-time table of powers.
+///
+/// Takes the named arguments `base`, `range` and the optional `ty`, and generates a fixed-size
+/// array of `base` raised to every exponent of `range`, e.g. to define a lookup table constant.
+/// With `ty`, every power is checked to fit in the type and emitted as a typed literal.
+///
+/// ```
+/// const POW2: [u256; 256] = pow_table!(base: 2, range: 0..256, ty: u256);
+/// const POW10: [u128; 39] = pow_table!(base: 10, range: 0..=38, ty: u128);
+/// assert_eq!(*POW2.span()[10], 1024);
+/// ```
+#[inline_macro]
+pub fn pow_table(token_stream: TokenStream) -> ProcMacroResult {
+    let macro_args = match TokenTreeParser::parse_inline_macro_args("pow_table", &token_stream) {
+        Ok(args) => args,
+        Err(diagnostics) => return error_result(diagnostics),
+    };
+
+    let mut base_arg = None;
+    let mut range_arg = None;
+    let mut ty_arg = None;
+    let mut errors = Vec::new();
+    for arg in &macro_args {
+        let slot = match arg.name.as_deref() {
+            Some("base") => &mut base_arg,
+            Some("range") => &mut range_arg,
+            Some("ty") => &mut ty_arg,
+            _ => {
+                errors.push(arg.error("Expected a `base`, `range` or `ty` named argument"));
+                continue;
+            }
+        };
+        if slot.replace(arg).is_some() {
+            errors.push(arg.error("Duplicate argument"));
+        }
+    }
+    for (arg, name) in [(base_arg, "base"), (range_arg, "range")] {
+        if arg.is_none() {
+            errors.push(Diagnostic::span_error(
+                token_stream_span(&token_stream),
+                format!("Missing `{name}` argument"),
+            ));
+        }
+    }
+    if !errors.is_empty() {
+        return error_result(Diagnostics::new(errors));
+    }
+    let (Some(base_arg), Some(range_arg)) = (base_arg, range_arg) else {
+        unreachable!("missing arguments are reported above");
     };
 
-    inline_macro
+    let Some(base) = base_arg.as_integer() else {
+        return error_result(
+            base_arg
+                .error(format!(
+                    "Invalid base value `{}`, expected an integer",
+                    base_arg.value
+                ))
+                .into(),
+        );
+    };
+    let (start, last) = match parse_exponent_range(range_arg) {
+        Ok(range) => range,
+        Err(diagnostic) => return error_result(diagnostic.into()),
+    };
+    let ty = match ty_arg.map(IntType::from_arg).transpose() {
+        Ok(ty) => ty,
+        Err(diagnostic) => return error_result(diagnostic.into()),
+    };
+
+    let mut entries = Vec::new();
+    let mut power: BigInt = pow(base.clone(), start);
+    for exp in start..=last {
+        let entry = match &ty {
+            Some(ty) if !ty.contains(&power) => {
+                return error_result(
+                    range_arg
+                        .error(format!(
+                            "{}^{} = {}",
+                            base_arg.value,
+                            exp,
+                            ty.out_of_range_message(&power)
+                        ))
+                        .into(),
+                )
+            }
+            Some(ty) => ty.literal(&power),
+            None => power.to_string(),
+        };
+        entries.push(entry);
+        power *= &base;
+    }
+
+    ProcMacroResult::new(
+        spanned(
+            format!("[{}]", entries.join(", ")),
+            &token_stream_span(&token_stream),
+        )
+        .build(),
+    )
 }
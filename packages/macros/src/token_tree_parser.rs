@@ -1,7 +1,13 @@
+use bigdecimal::num_bigint::BigInt;
+use cairo_lang_filesystem::ids::{FileKind, FileLongId, VirtualFile};
+use cairo_lang_macro::{Diagnostic, Diagnostics, TextSpan, TokenStream, TokenTree};
+use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_parser::utils::SimpleParserDatabase;
-use cairo_lang_syntax::node::ast::TokenTreeNode;
-use cairo_lang_syntax::node::TypedSyntaxNode;
-use cairo_lang_utils::Upcast;
+use cairo_lang_syntax::node::ast::{ArgListParenthesized, Expr, TokenTreeNode};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::{Intern, Upcast};
 
 /// Comprehensive TokenTreeNode parsing examples for Cairo 2.12.0
 ///
@@ -9,39 +15,321 @@ use cairo_lang_utils::Upcast;
 /// which is the new format for macro arguments in Cairo 2.12.0
 pub struct TokenTreeParser;
 
+/// A single macro argument, either positional (`10`) or named (`base = 10` or `base: 10`).
+pub struct MacroArg {
+    /// Name of a named argument, `None` for positional ones.
+    pub name: Option<String>,
+    /// Source text of the argument value.
+    pub value: String,
+    /// Span of the whole argument in the user's code.
+    pub span: TextSpan,
+}
+
+impl MacroArg {
+    /// Value of an integer literal argument, see [`parse_integer_literal`].
+    pub fn as_integer(&self) -> Option<BigInt> {
+        parse_integer_literal(&self.value)
+    }
+
+    /// Content of a string literal argument, e.g. `id` for `"id"`.
+    pub fn as_string(&self) -> Option<String> {
+        let content = self.value.strip_prefix('"')?.strip_suffix('"')?;
+
+        let mut result = String::new();
+        let mut chars = content.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                result.push(c);
+                continue;
+            }
+            match chars.next()? {
+                'n' => result.push('\n'),
+                't' => result.push('\t'),
+                'r' => result.push('\r'),
+                '0' => result.push('\0'),
+                escaped => result.push(escaped),
+            }
+        }
+        Some(result)
+    }
+
+    /// Error on this argument.
+    pub fn error(&self, message: impl ToString) -> Diagnostic {
+        Diagnostic::span_error(self.span.clone(), message)
+    }
+}
+
+/// A terminal token of the arguments, with its offsets relative to the start of the arguments.
+struct ArgToken {
+    kind: SyntaxKind,
+    text: String,
+    start: usize,
+    end: usize,
+}
+
 impl TokenTreeParser {
-    /// Method 1: Simple string-based parsing
-    ///
-    /// Pros: Fast, simple to implement
-    /// Cons: Less accurate, can't handle complex nested expressions well
+    /// Parses the arguments of an inline macro, e.g. `(f(1, 2), "a, b", base = 0x10)`.
     ///
-    /// Use when: You have simple comma-separated literal arguments
-    pub fn parse_simple_string(
+    /// Arguments are split on the commas outside of nested delimiters and string literals,
+    /// and keep their span so diagnostics can point at them.
+    /// `origin` is the offset of the token tree in the user's code.
+    pub fn parse_args(
         token_tree: &TokenTreeNode,
         db: &SimpleParserDatabase,
-    ) -> Vec<String> {
-        let token_str = token_tree.as_syntax_node().get_text(db.upcast());
-        let content = token_str.trim_start_matches('(').trim_end_matches(')');
+        origin: u32,
+    ) -> Result<Vec<MacroArg>, Diagnostics> {
+        parse_parenthesized(&token_tree.as_syntax_node(), db.upcast(), origin)
+    }
+
+    /// Parses the arguments of an attribute, e.g. `rename = "id"` in `#[json(rename = "id")]`.
+    ///
+    /// The attribute must come from a token stream parsed with its original offsets.
+    pub fn parse_attribute_args(
+        args: &ArgListParenthesized,
+        db: &dyn SyntaxGroup,
+    ) -> Result<Vec<MacroArg>, Diagnostics> {
+        let node = args.as_syntax_node();
+        parse_parenthesized(&node, db, node.offset(db).as_u32())
+    }
 
-        if content.is_empty() {
-            return vec![];
+    /// Parses the token stream received by the inline macro `name` into its arguments.
+    ///
+    /// The token stream of `pow!(10, 3)` is only `(10, 3)`.
+    pub fn parse_inline_macro_args(
+        name: &str,
+        token_stream: &TokenStream,
+    ) -> Result<Vec<MacroArg>, Diagnostics> {
+        let db = SimpleParserDatabase::default();
+        // Create a virtual file that will be parsed.
+        let file = FileLongId::Virtual(VirtualFile {
+            parent: None,
+            name: "parser_input".into(),
+            content: format!("{name}!{token_stream}").into(), // easiest workaround after change
+            code_mappings: [].into(),
+            kind: FileKind::Expr, // this part is different than db.parse_virtual
+            original_item_removed: false,
+        })
+        .intern(&db);
+
+        // Could fail if there was a parsing error but it shouldn't happen as the file has already
+        // been parsed once to reach this macro.
+        let invalid_arguments = || {
+            Diagnostics::from(Diagnostic::span_error(
+                token_stream_span(token_stream),
+                format!("Invalid `{name}!` arguments"),
+            ))
+        };
+        let node = db.file_expr_syntax(file).map_err(|_| invalid_arguments())?;
+        let Expr::InlineMacro(inline_macro) = node else {
+            return Err(invalid_arguments());
+        };
+
+        Self::parse_args(
+            &inline_macro.arguments(db.upcast()),
+            &db,
+            token_stream_origin(token_stream),
+        )
+    }
+
+    /// Parses the token stream received by the inline macro `name`, which takes a single
+    /// positional argument, e.g. `-3.5` in `dec!(-3.5)`.
+    pub fn parse_single_inline_macro_arg(
+        name: &str,
+        token_stream: &TokenStream,
+    ) -> Result<MacroArg, Diagnostics> {
+        let mut args = Self::parse_inline_macro_args(name, token_stream)?;
+        if args.len() != 1 {
+            return Err(Diagnostic::span_error(
+                token_stream_span(token_stream),
+                format!(
+                    "Invalid number of arguments, expected 1 but got {}",
+                    args.len()
+                ),
+            )
+            .into());
+        }
+        let arg = args.remove(0);
+        if arg.name.is_some() {
+            return Err(arg
+                .error(format!("`{name}!` doesn't take named arguments"))
+                .into());
         }
+        Ok(arg)
+    }
+}
 
-        content
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect()
+/// Span covering all the arguments of an inline macro in the user's code.
+pub fn token_stream_span(token_stream: &TokenStream) -> TextSpan {
+    let end = token_stream.tokens.last().map_or(0, |tree| match tree {
+        TokenTree::Ident(token) => token.span.end,
+    });
+    TextSpan::new(token_stream_origin(token_stream), end)
+}
+
+/// Offset of the first token of the macro arguments in the user's code.
+fn token_stream_origin(token_stream: &TokenStream) -> u32 {
+    token_stream.tokens.first().map_or(0, |tree| match tree {
+        TokenTree::Ident(token) => token.span.start,
+    })
+}
+
+/// Parses an integer literal: decimal, hex (`0x`), octal (`0o`) or binary (`0b`),
+/// optionally negative, with `_` separators and a type suffix, e.g. `-0x1_000_u64`.
+pub fn parse_integer_literal(literal: &str) -> Option<BigInt> {
+    let literal = literal.trim();
+    let (is_negative, literal) = match literal.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, literal),
+    };
+
+    let (radix, digits) = match literal.get(..2) {
+        Some("0x" | "0X") => (16, &literal[2..]),
+        Some("0o" | "0O") => (8, &literal[2..]),
+        Some("0b" | "0B") => (2, &literal[2..]),
+        _ => (10, literal),
+    };
+
+    // Drop a type suffix such as `_u64`, which can't be mistaken for hex digits as it starts
+    // with a letter outside of `a-f`.
+    let digits = match digits.rfind('_') {
+        Some(idx)
+            if digits[idx + 1..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() && !c.is_digit(radix)) =>
+        {
+            &digits[..idx]
+        }
+        _ => digits,
+    };
+
+    let digits = digits.replace('_', "");
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
     }
+
+    let value = BigInt::parse_bytes(digits.as_bytes(), radix)?;
+    Some(if is_negative { -value } else { value })
+}
+
+/// Splits the content of a parenthesized node into arguments on the top-level commas.
+fn parse_parenthesized(
+    node: &SyntaxNode,
+    db: &dyn SyntaxGroup,
+    origin: u32,
+) -> Result<Vec<MacroArg>, Diagnostics> {
+    let text = node.get_text(db);
+    let tokens = collect_tokens(node, db);
+    let to_span =
+        |start: usize, end: usize| TextSpan::new(origin + start as u32, origin + end as u32);
+
+    // Skip the enclosing parentheses.
+    let inner = match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last))
+            if tokens.len() >= 2
+                && first.kind == SyntaxKind::TerminalLParen
+                && last.kind == SyntaxKind::TerminalRParen =>
+        {
+            &tokens[1..tokens.len() - 1]
+        }
+        _ => {
+            return Err(Diagnostic::span_error(
+                to_span(0, text.len()),
+                "Expected arguments in parentheses",
+            )
+            .into())
+        }
+    };
+
+    let mut groups: Vec<&[ArgToken]> = Vec::new();
+    let mut depth = 0usize;
+    let mut group_start = 0;
+    for (i, token) in inner.iter().enumerate() {
+        match token.kind {
+            SyntaxKind::TerminalLParen
+            | SyntaxKind::TerminalLBrack
+            | SyntaxKind::TerminalLBrace => depth += 1,
+            SyntaxKind::TerminalRParen
+            | SyntaxKind::TerminalRBrack
+            | SyntaxKind::TerminalRBrace => depth = depth.saturating_sub(1),
+            SyntaxKind::TerminalComma if depth == 0 => {
+                groups.push(&inner[group_start..i]);
+                group_start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    groups.push(&inner[group_start..]);
+
+    let mut args = Vec::new();
+    let mut errors = Vec::new();
+    let group_count = groups.len();
+    for (i, group) in groups.into_iter().enumerate() {
+        let (Some(first), Some(last)) = (group.first(), group.last()) else {
+            // A trailing comma leaves an empty last group, any other empty group is an error.
+            if i + 1 != group_count {
+                errors.push(Diagnostic::span_error(
+                    to_span(0, text.len()),
+                    "Empty macro argument",
+                ));
+            }
+            continue;
+        };
+
+        let (name, value_tokens) = match group {
+            [name, sep, value @ ..]
+                if name.kind == SyntaxKind::TerminalIdentifier
+                    && matches!(sep.kind, SyntaxKind::TerminalEq | SyntaxKind::TerminalColon) =>
+            {
+                (Some(name.text.clone()), value)
+            }
+            _ => (None, group),
+        };
+
+        let (Some(value_first), Some(value_last)) = (value_tokens.first(), value_tokens.last())
+        else {
+            errors.push(Diagnostic::span_error(
+                to_span(first.start, last.end),
+                format!("Missing value for argument `{}`", name.unwrap_or_default()),
+            ));
+            continue;
+        };
+
+        args.push(MacroArg {
+            name,
+            value: text[value_first.start..value_last.end].to_string(),
+            span: to_span(first.start, last.end),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(args)
+    } else {
+        Err(Diagnostics::new(errors))
+    }
+}
+
+/// Collects the terminal tokens under `node` in source order.
+fn collect_tokens(node: &SyntaxNode, db: &dyn SyntaxGroup) -> Vec<ArgToken> {
+    let base = node.offset(db).as_u32() as usize;
+    node.descendants(db)
+        .filter(|node| node.kind(db).is_terminal())
+        .map(|node| {
+            let span = node.span_without_trivia(db);
+            ArgToken {
+                kind: node.kind(db),
+                text: node.get_text_without_trivia(db),
+                start: span.start.as_u32() as usize - base,
+                end: span.end.as_u32() as usize - base,
+            }
+        })
+        .filter(|token| !token.text.is_empty())
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cairo_lang_filesystem::ids::{FileKind, FileLongId, VirtualFile};
-    use cairo_lang_parser::db::ParserGroup;
-    use cairo_lang_syntax::node::ast::Expr;
-    use cairo_lang_utils::Intern;
 
     fn create_test_token_tree(input: &str) -> (TokenTreeNode, SimpleParserDatabase) {
         let db = SimpleParserDatabase::default();
@@ -65,17 +353,25 @@ mod tests {
         }
     }
 
+    fn parse_texts(token_tree: &TokenTreeNode, db: &SimpleParserDatabase) -> Vec<String> {
+        TokenTreeParser::parse_args(token_tree, db, 0)
+            .unwrap_or_else(|_| panic!("Parse error"))
+            .into_iter()
+            .map(|arg| arg.value)
+            .collect()
+    }
+
     #[test]
     fn test_simple_string_parsing() {
         let (token_tree, db) = create_test_token_tree("(2, 10)");
-        let args = TokenTreeParser::parse_simple_string(&token_tree, &db);
+        let args = parse_texts(&token_tree, &db);
         assert_eq!(args, vec!["2", "10"]);
     }
 
     #[test]
     fn test_simple_string_parsing_with_expressions() {
         let (token_tree, db) = create_test_token_tree("(2 + 3, 4 * 5)");
-        let args = TokenTreeParser::parse_simple_string(&token_tree, &db);
+        let args = parse_texts(&token_tree, &db);
         assert_eq!(args.len(), 2);
         // The exact format may vary based on tokenization, but should contain the numbers
         assert!(args[0].contains("2") && args[0].contains("3"));
@@ -85,10 +381,67 @@ mod tests {
     #[test]
     fn test_simple_string_parsing_multiple_args() {
         let (token_tree, db) = create_test_token_tree("(42, some_var, 100)");
-        let args = TokenTreeParser::parse_simple_string(&token_tree, &db);
+        let args = parse_texts(&token_tree, &db);
         assert_eq!(args.len(), 3);
         assert_eq!(args[0].trim(), "42");
         assert!(args[1].trim().contains("some_var"));
         assert_eq!(args[2].trim(), "100");
     }
+
+    #[test]
+    fn test_simple_string_parsing_spans() {
+        let (token_tree, db) = create_test_token_tree("(42,  some_var)");
+        let args = TokenTreeParser::parse_args(&token_tree, &db, 100)
+            .unwrap_or_else(|_| panic!("Parse error"));
+        assert_eq!(args.len(), 2);
+        assert_eq!(args[0].value, "42");
+        assert_eq!((args[0].span.start, args[0].span.end), (101, 103));
+        assert_eq!(args[1].value, "some_var");
+        assert_eq!((args[1].span.start, args[1].span.end), (106, 114));
+    }
+
+    #[test]
+    fn test_nested_delimiters() {
+        let (token_tree, db) = create_test_token_tree("(f(1, 2), [3, 4], 5)");
+        let args = parse_texts(&token_tree, &db);
+        assert_eq!(args, vec!["f(1, 2)", "[3, 4]", "5"]);
+    }
+
+    #[test]
+    fn test_string_with_commas() {
+        let (token_tree, db) = create_test_token_tree("(\"a, b\", 1,)");
+        let args = TokenTreeParser::parse_args(&token_tree, &db, 0)
+            .unwrap_or_else(|_| panic!("Parse error"));
+        assert_eq!(args.len(), 2);
+        assert_eq!(args[0].as_string().as_deref(), Some("a, b"));
+        assert_eq!(args[1].as_integer(), Some(BigInt::from(1)));
+    }
+
+    #[test]
+    fn test_named_args() {
+        let (token_tree, db) = create_test_token_tree("(base = 2, range: 0..256, 3)");
+        let args = TokenTreeParser::parse_args(&token_tree, &db, 0)
+            .unwrap_or_else(|_| panic!("Parse error"));
+        assert_eq!(args.len(), 3);
+        assert_eq!(args[0].name.as_deref(), Some("base"));
+        assert_eq!(args[0].value, "2");
+        assert_eq!(args[1].name.as_deref(), Some("range"));
+        assert_eq!(args[1].value, "0..256");
+        assert_eq!(args[2].name, None);
+    }
+
+    #[test]
+    fn test_integer_literals() {
+        assert_eq!(parse_integer_literal("1_000"), Some(BigInt::from(1000)));
+        assert_eq!(parse_integer_literal("0xff"), Some(BigInt::from(255)));
+        assert_eq!(parse_integer_literal("0xab_cd"), Some(BigInt::from(0xabcd)));
+        assert_eq!(parse_integer_literal("0o17"), Some(BigInt::from(15)));
+        assert_eq!(parse_integer_literal("0b101"), Some(BigInt::from(5)));
+        assert_eq!(parse_integer_literal("- 42"), Some(BigInt::from(-42)));
+        assert_eq!(parse_integer_literal("10_u64"), Some(BigInt::from(10)));
+        assert_eq!(parse_integer_literal("0xff_u8"), Some(BigInt::from(255)));
+        assert_eq!(parse_integer_literal("some_var"), None);
+        assert_eq!(parse_integer_literal("0x"), None);
+        assert_eq!(parse_integer_literal("1.5"), None);
+    }
 }
@@ -1,66 +1,175 @@
-use crate::parse::{parse_struct_info, StructInfo};
-use cairo_lang_macro::{derive_macro, ProcMacroResult, TokenStream};
-
-fn generate_zero_trait_impl(s: &StructInfo) -> String {
-    let generic_params = s
-        .generic_params
-        .as_ref()
-        .map_or(String::new(), |params| format!("<{}>", params.join(", ")));
-
-    let trait_bounds = s
-        .generic_params
-        .as_ref()
-        .map_or_else(String::new, |params| {
-            let bounds = params
-                .iter()
-                .flat_map(|param| {
-                    vec![
-                        format!("+core::num::traits::Zero<{}>", param),
-                        format!("+core::traits::Drop<{}>", param),
-                    ]
-                })
-                .collect::<Vec<_>>()
-                .join(",\n");
-            format!("<{},\n{}>", params.join(", "), bounds)
-        });
-
-    let zero_fn = s
-        .members
+use crate::codegen::{spanned, CodeBuilder};
+use crate::parse::{expand_derive, find_attribute, FieldInfo, ItemInfo, ItemKind, VariantInfo};
+use cairo_lang_macro::{
+    derive_macro, executable_attribute, Diagnostic, Diagnostics, ProcMacroResult, TokenStream,
+};
+
+// Declares the `#[zero]` helper attribute so that the compiler accepts it on variants.
+executable_attribute!("zero");
+
+fn generate_zero_trait_impl(s: &ItemInfo, fields: &[FieldInfo]) -> CodeBuilder {
+    let generic_params = s.generic_args();
+
+    let bounds = s
+        .generic_field_types(fields)
+        .iter()
+        .flat_map(|ty| {
+            vec![
+                format!("+core::num::traits::Zero<{}>", ty),
+                format!("+core::traits::Drop<{}>", ty),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let trait_bounds = s.impl_generic_params(&bounds);
+
+    let zero_fn = fields.iter().map(|field| {
+        spanned(
+            format!("{}: core::num::traits::Zero::zero()", field.name),
+            &field.span,
+        )
+    });
+
+    let is_zero_fn = fields
         .iter()
-        .map(|member| format!("{member}: core::num::traits::Zero::zero()"))
-        .collect::<Vec<_>>()
-        .join(", ");
+        .map(|field| spanned(format!("self.{}.is_zero()", field.name), &field.span));
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
+        "\n
+impl {0}ZeroImpl{1}
+of core::num::traits::Zero<{0}{2}> {{
+    fn zero() -> {0}{2} {{
+        {0} {{ ",
+        s.name, trait_bounds, generic_params
+    ));
+    code.push_joined(zero_fn, ", ");
+    code.push(format!(
+        " }}
+    }}
+
+    fn is_zero(self: @{0}{1}) -> bool {{
+        ",
+        s.name, generic_params
+    ));
+    code.push_joined(is_zero_fn, " && ");
+    code.push(format!(
+        "
+    }}
+
+    fn is_non_zero(self: @{0}{1}) -> bool {{
+        !self.is_zero()
+    }}
+}}\n",
+        s.name, generic_params
+    ));
+    code
+}
 
-    let is_zero_fn = s
-        .members
+/// The variant of the enum marked with `#[zero]`.
+fn zero_variant<'a>(
+    s: &ItemInfo,
+    variants: &'a [VariantInfo],
+) -> Result<&'a VariantInfo, Diagnostics> {
+    let mut marked = variants
         .iter()
-        .map(|member| format!("self.{member}.is_zero()"))
-        .collect::<Vec<_>>()
-        .join(" && ");
+        .filter_map(|variant| Some((variant, find_attribute(&variant.attributes, "zero")?)));
+    let Some((zero, attr)) = marked.next() else {
+        return Err(Diagnostic::span_error(
+            s.span.clone(),
+            "`Zero` on an enum requires marking the zero variant with `#[zero]`",
+        )
+        .into());
+    };
+
+    let mut errors = Vec::new();
+    if !attr.args.is_empty() {
+        errors.push(attr.error("`#[zero]` doesn't take arguments"));
+    }
+    for (_, attr) in marked {
+        errors.push(attr.error("only one variant can be marked with `#[zero]`"));
+    }
+    if errors.is_empty() {
+        Ok(zero)
+    } else {
+        Err(Diagnostics::new(errors))
+    }
+}
+
+fn generate_enum_zero_trait_impl(
+    s: &ItemInfo,
+    variants: &[VariantInfo],
+) -> Result<CodeBuilder, Diagnostics> {
+    let zero = zero_variant(s, variants)?;
+    let generic_params = s.generic_args();
+
+    let bounds = match &zero.payload {
+        Some(ty) if s.is_generic_type(ty) => vec![
+            format!("+core::num::traits::Zero<{}>", ty),
+            format!("+core::traits::Drop<{}>", ty),
+        ],
+        _ => Vec::new(),
+    };
+    let trait_bounds = s.impl_generic_params(&bounds);
 
-    format!(
+    let zero_fn = match zero.payload {
+        Some(_) => format!("{}::{}(core::num::traits::Zero::zero())", s.name, zero.name),
+        None => format!("{}::{}", s.name, zero.name),
+    };
+
+    // Every variant gets its own arm, a wildcard arm would be unreachable for single variant
+    // enums.
+    let is_zero_arms = variants.iter().map(|variant| {
+        let is_zero = std::ptr::eq(variant, zero);
+        let code = match (&variant.payload, is_zero) {
+            (Some(_), true) => format!(
+                "{}::{}(value) => core::num::traits::Zero::is_zero(value),",
+                s.name, variant.name
+            ),
+            (Some(_), false) => format!("{}::{}(_) => false,", s.name, variant.name),
+            (None, is_zero) => format!("{}::{} => {},", s.name, variant.name, is_zero),
+        };
+        spanned(code, &variant.span)
+    });
+
+    let mut code = CodeBuilder::default();
+    code.push(format!(
         "\n
 impl {0}ZeroImpl{1}
 of core::num::traits::Zero<{0}{2}> {{
     fn zero() -> {0}{2} {{
-        {0} {{ {3} }}
+        ",
+        s.name, trait_bounds, generic_params
+    ));
+    code.push_spanned(zero_fn, &zero.span);
+    code.push(format!(
+        "
     }}
 
-    fn is_zero(self: @{0}{2}) -> bool {{
-        {4}
+    fn is_zero(self: @{0}{1}) -> bool {{
+        match self {{
+            ",
+        s.name, generic_params
+    ));
+    code.push_joined(is_zero_arms, "\n            ");
+    code.push(format!(
+        "
+        }}
     }}
 
-    fn is_non_zero(self: @{0}{2}) -> bool {{
+    fn is_non_zero(self: @{0}{1}) -> bool {{
         !self.is_zero()
     }}
 }}\n",
-        s.name, trait_bounds, generic_params, zero_fn, is_zero_fn
-    )
+        s.name, generic_params
+    ));
+    Ok(code)
 }
 
 /// Adds implementation of the `core::num::traits::Zero` trait.
 ///
-/// All members of the struct must already implement the `Zero` trait.
+/// For a struct, all members of the struct must already implement the `Zero` trait.
+/// For an enum, the variant representing zero must be marked with `#[zero]`, its payload
+/// if any must already implement the `Zero` trait.
 ///
 /// ```
 /// #[derive(Zero, PartialEq, Debug)]
@@ -72,10 +181,21 @@ of core::num::traits::Zero<{0}{2}> {{
 /// assert_eq!(Point { x: 0, y: 0 }, Zero::zero());
 /// assert!(Point { x: 0, y: 0 }.is_zero());
 /// assert!(Point { x: 1, y: 0 }.is_non_zero());
+///
+/// #[derive(Zero, PartialEq, Debug)]
+/// enum Amount {
+///     #[zero]
+///     Nothing,
+///     Some: u64,
+/// }
+///
+/// assert_eq!(Amount::Nothing, Zero::zero());
+/// assert!(Amount::Some(1).is_non_zero());
 /// ```
 #[derive_macro]
 pub fn zero(token_stream: TokenStream) -> ProcMacroResult {
-    let s = parse_struct_info(token_stream);
-
-    ProcMacroResult::new(TokenStream::new(generate_zero_trait_impl(&s)))
+    expand_derive(token_stream, |s| match &s.kind {
+        ItemKind::Struct(fields) => Ok(generate_zero_trait_impl(s, fields)),
+        ItemKind::Enum(variants) => generate_enum_zero_trait_impl(s, variants),
+    })
 }
@@ -1,5 +1,12 @@
+mod test_arithmetic_traits;
+mod test_bounded_trait;
+mod test_const_eval;
 mod test_event_macro;
+mod test_fixed_point;
+mod test_int_literals;
 mod test_num_traits;
+mod test_one_trait;
+mod test_partial_ord;
 mod test_pow;
 mod test_pow_inline;
 mod test_zero_trait;
@@ -0,0 +1,82 @@
+use core::num::traits::{
+    CheckedAdd, CheckedMul, CheckedSub, OverflowingAdd, OverflowingMul, OverflowingSub,
+    SaturatingAdd, SaturatingMul, SaturatingSub, WrappingAdd, WrappingMul, WrappingSub,
+};
+
+// a basic struct
+#[derive(
+    CheckedAdd,
+    CheckedSub,
+    CheckedMul,
+    WrappingAdd,
+    WrappingSub,
+    WrappingMul,
+    SaturatingAdd,
+    SaturatingSub,
+    SaturatingMul,
+    OverflowingAdd,
+    OverflowingSub,
+    OverflowingMul,
+    Copy,
+    Debug,
+    Drop,
+    PartialEq,
+)]
+struct Balances {
+    available: u8,
+    locked: u16,
+}
+
+// a generic struct
+#[derive(CheckedAdd, OverflowingSub, Copy, Debug, Drop, PartialEq)]
+struct G<T> {
+    x: T,
+    y: u8,
+}
+
+#[test]
+fn test_checked_derive() {
+    let a = Balances { available: 10, locked: 5 };
+    let b = Balances { available: 3, locked: 7 };
+    assert!(a.checked_add(b) == Option::Some(Balances { available: 13, locked: 12 }));
+    assert!(a.checked_sub(b).is_none());
+    assert!(a.checked_mul(b) == Option::Some(Balances { available: 30, locked: 35 }));
+    assert!(Balances { available: 200, locked: 1 }.checked_add(a).is_none());
+
+    let g1: G<u32> = G { x: 1, y: 2 };
+    let g2: G<u32> = G { x: 3, y: 255 };
+    assert!(g1.checked_add(g1) == Option::Some(G { x: 2, y: 4 }));
+    assert!(g1.checked_add(g2).is_none());
+}
+
+#[test]
+fn test_wrapping_derive() {
+    let a = Balances { available: 250, locked: 5 };
+    let b = Balances { available: 10, locked: 7 };
+    assert!(a.wrapping_add(b) == Balances { available: 4, locked: 12 });
+    assert!(b.wrapping_sub(a) == Balances { available: 16, locked: 2 });
+    assert!(a.wrapping_mul(b) == Balances { available: 196, locked: 35 });
+}
+
+#[test]
+fn test_saturating_derive() {
+    let a = Balances { available: 250, locked: 5 };
+    let b = Balances { available: 10, locked: 7 };
+    assert!(a.saturating_add(b) == Balances { available: 255, locked: 12 });
+    assert!(a.saturating_sub(b) == Balances { available: 240, locked: 0 });
+    assert!(a.saturating_mul(b) == Balances { available: 255, locked: 35 });
+}
+
+#[test]
+fn test_overflowing_derive() {
+    let a = Balances { available: 250, locked: 5 };
+    let b = Balances { available: 10, locked: 7 };
+    assert!(a.overflowing_add(b) == (Balances { available: 4, locked: 12 }, true));
+    assert!(b.overflowing_add(b) == (Balances { available: 20, locked: 14 }, false));
+    assert!(a.overflowing_sub(b) == (Balances { available: 240, locked: 65534 }, true));
+    assert!(b.overflowing_mul(b) == (Balances { available: 100, locked: 49 }, false));
+
+    let g1: G<u32> = G { x: 1, y: 2 };
+    let g2: G<u32> = G { x: 3, y: 1 };
+    assert!(g2.overflowing_sub(g1) == (G { x: 2, y: 255 }, true));
+}
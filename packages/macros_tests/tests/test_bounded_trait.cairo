@@ -0,0 +1,40 @@
+use core::num::traits::Bounded;
+
+// a basic struct
+#[derive(Bounded, Debug, Drop, PartialEq)]
+struct B {
+    pub a: u8,
+    b: i16,
+}
+
+// a generic struct
+#[derive(Bounded, Debug, Drop, PartialEq)]
+struct G<T1, T2> {
+    x: T1,
+    pub y: T2,
+}
+
+// a complex struct
+#[derive(Bounded, Debug, Drop, PartialEq)]
+struct C {
+    pub g: G<u8, u32>,
+    i: u64,
+}
+
+#[test]
+fn test_bounded_derive() {
+    let min: B = Bounded::MIN;
+    let max: B = Bounded::MAX;
+    assert!(min == B { a: 0, b: -0x8000 });
+    assert!(max == B { a: 0xff, b: 0x7fff });
+
+    let min: G<u8, i8> = Bounded::MIN;
+    let max: G<u8, i8> = Bounded::MAX;
+    assert!(min == G { x: 0, y: -0x80 });
+    assert!(max == G { x: 0xff, y: 0x7f });
+
+    let min: C = Bounded::MIN;
+    let max: C = Bounded::MAX;
+    assert!(min == C { g: G { x: 0, y: 0 }, i: 0 });
+    assert!(max == C { g: G { x: 0xff, y: 0xffffffff }, i: 0xffffffffffffffff });
+}
@@ -0,0 +1,27 @@
+const ED25519_P: u256 = const_eval!(2 ** 255 - 19, u256);
+const WAD: u256 = const_eval!(10 ** 18, u256);
+const MASK: u128 = const_eval!((1 << 64) - 1, u128);
+
+#[test]
+fn test_const_eval_macro() {
+    assert!(ED25519_P == 0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed);
+    assert!(WAD == 1000000000000000000);
+    assert!(MASK == 0xffffffffffffffff);
+    assert!(const_eval!(1 + 2 * 3) == 7_u8);
+    assert!(const_eval!((1 + 2) * 3, u8) == 9);
+    assert!(const_eval!(2 ** 3 ** 2, u16) == 512);
+    assert!(const_eval!(-7 / 2, i8) == -3);
+    assert!(const_eval!(-7 % 2, i8) == -1);
+    assert!(const_eval!(0xf0 & 0x3c | 0x01 ^ 0x03, u8) == 0x32);
+    assert!(const_eval!(0x100 >> 4, u16) == 16);
+}
+
+#[test]
+fn test_const_eval_functions() {
+    assert!(const_eval!(min(3, 1, 2), u8) == 1);
+    assert!(const_eval!(max(3, 1, 2), u8) == 3);
+    assert!(const_eval!(gcd(12, 18), u8) == 6);
+    assert!(const_eval!(inv_mod(3, 7), u8) == 5);
+    assert!(const_eval!(pow_mod(3, 200, 7), u8) == 2);
+    assert!(const_eval!(inv_mod(2, 2 ** 255 - 19), u256) == (ED25519_P + 1) / 2);
+}
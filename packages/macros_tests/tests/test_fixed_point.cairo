@@ -0,0 +1,41 @@
+use alexandria_math::decimal::{Decimal, DecimalTrait};
+
+#[test]
+fn test_dec_macro() {
+    let x: Decimal = dec!(-3.035);
+    assert_eq!(x, DecimalTrait::from_raw_parts_signed(3, 35_000_000_000_000_000, true));
+    assert_eq!(dec!(1.05), DecimalTrait::from_raw_parts(1, 50_000_000_000_000_000));
+    assert_eq!(dec!(42), DecimalTrait::from_int(42));
+    assert_eq!(dec!(0.000_000_000_000_000_001), DecimalTrait::from_raw_parts(0, 1));
+    assert_eq!(dec!("1e-18"), DecimalTrait::from_raw_parts(0, 1));
+    assert_eq!(dec!("2.5e3"), DecimalTrait::from_int(2500));
+}
+
+#[test]
+fn test_dec_macro_bounds() {
+    assert_eq!(
+        dec!(18446744073709551615.999999999999999999),
+        DecimalTrait::from_raw_parts(0xffffffffffffffff, 999_999_999_999_999_999),
+    );
+    assert_eq!(dec!(-0.0), DecimalTrait::from_int(0));
+}
+
+const ONE_AND_A_HALF: u256 = wad!(1.5);
+const NANO: u256 = ray!(0.000000001);
+
+#[test]
+fn test_wad_macro() {
+    assert_eq!(ONE_AND_A_HALF, 1_500_000_000_000_000_000);
+    assert_eq!(wad!(1), 1_000_000_000_000_000_000);
+    assert_eq!(wad!(0), 0);
+    assert_eq!(wad!(0.000_000_000_000_000_001), 1);
+    assert_eq!(wad!("2.5e3"), 2_500_000_000_000_000_000_000);
+}
+
+#[test]
+fn test_ray_macro() {
+    assert_eq!(NANO, 1_000_000_000_000_000_000);
+    assert_eq!(ray!(1), 1_000_000_000_000_000_000_000_000_000);
+    assert_eq!(ray!(0.000_000_000_000_000_000_000_000_001), 1);
+    assert_eq!(ray!(0.5), 500_000_000_000_000_000_000_000_000);
+}
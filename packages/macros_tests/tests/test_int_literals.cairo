@@ -0,0 +1,43 @@
+use alexandria_math::i257::{I257Impl, i257};
+use core::integer::u512;
+use core::num::traits::Bounded;
+
+const U256_MAX: u256 = u256!(
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+);
+
+const I257_MIN: i257 = i257!(
+    "-115792089237316195423570985008687907853269984665640564039457584007913129639935"
+);
+
+#[test]
+fn test_u256_macro() {
+    assert_eq!(U256_MAX, Bounded::<u256>::MAX);
+    assert_eq!(u256!(0), 0);
+    assert_eq!(u256!(0x1_00000000000000000000000000000002), u256 { low: 2, high: 1 });
+    assert_eq!(u256!("340282366920938463463374607431768211456"), u256 { low: 0, high: 1 });
+}
+
+#[test]
+fn test_u512_macro() {
+    let x: u512 = u512!(0x4_00000000000000000000000000000003_00000000000000000000000000000002_00000000000000000000000000000001);
+    assert_eq!(x.limb0, 1);
+    assert_eq!(x.limb1, 2);
+    assert_eq!(x.limb2, 3);
+    assert_eq!(x.limb3, 4);
+
+    let zero: u512 = u512!(0);
+    assert_eq!(zero.limb0 + zero.limb1 + zero.limb2 + zero.limb3, 0);
+}
+
+#[test]
+fn test_i257_macro() {
+    let x: i257 = i257!(-0x1234);
+    assert!(x == I257Impl::new(0x1234, true));
+    assert!(x.is_negative());
+    assert_eq!(x.abs(), 0x1234);
+
+    assert!(i257!(0) == I257Impl::new(0, false));
+    assert!(i257!("42") == I257Impl::new(42, false));
+    assert!(I257_MIN == I257Impl::new(Bounded::<u256>::MAX, true));
+}
@@ -13,6 +13,67 @@ struct G<T1, T2> {
     z: T2,
 }
 
+// a generic struct with its own trait bounds
+#[derive(Add, AddAssign, Sub, SubAssign, Debug, Drop, PartialEq)]
+struct Bounded<T, +Drop<T>> {
+    x: T,
+    y: T,
+}
+
+// a generic struct with concrete fields and an unused parameter
+#[derive(Add, AddAssign, Debug, Drop, PartialEq)]
+struct Mixed<T, U> {
+    amount: T,
+    nested: G<T, u64>,
+    count: u32,
+}
+
+// a newtype, Cairo has no tuple structs
+#[derive(Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Debug, Drop, PartialEq)]
+struct Wad {
+    value: u256,
+}
+
+// a signed struct
+#[derive(Neg, Rem, RemAssign, Debug, Drop, PartialEq)]
+struct S {
+    x: i32,
+    y: i64,
+}
+
+// a struct of flags
+#[derive(BitAnd, BitOr, BitXor, BitNot, Debug, Drop, PartialEq)]
+struct F<T> {
+    read: T,
+    write: u16,
+}
+
+// a struct scaled by a primitive
+#[derive(ScalarMul, ScalarDiv, ScalarMulAssign, ScalarDivAssign, Debug, Drop, PartialEq)]
+#[scalar(u64)]
+struct V {
+    x: u64,
+    y: u64,
+}
+
+// a struct scaled member by member, including a nested scalable struct
+#[derive(ScalarMul, ScalarMulAssign, Debug, Drop, PartialEq)]
+#[scalar(u64)]
+struct W<T> {
+    v: V,
+    t: T,
+}
+
+// a struct mixing identifiers and quantities
+#[derive(Add, AddAssign, Sub, SubAssign, Neg, Debug, Drop, PartialEq)]
+struct Position<T> {
+    #[op(skip)]
+    id: felt252,
+    #[op(assert_eq)]
+    market: T,
+    amount: i128,
+    debt: i128,
+}
 
 // a complex struct
 #[derive(Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Debug, Drop, PartialEq)]
@@ -165,3 +226,137 @@ fn test_divassign_derive() {
     c1 /= c2;
     assert!(c1 == C { g: G { x: 2, y: 2, z: 16 }, i: 2, j: 2 });
 }
+
+#[test]
+fn test_derive_with_struct_bounds() {
+    let b1: Bounded<u32> = Bounded { x: 1, y: 2 };
+    let b2: Bounded<u32> = Bounded { x: 3, y: 4 };
+    assert!(b1 + b2 == Bounded { x: 4, y: 6 });
+
+    let mut b3: Bounded<u32> = Bounded { x: 5, y: 7 };
+    b3 -= Bounded { x: 1, y: 2 };
+    assert!(b3 == Bounded { x: 4, y: 5 });
+}
+
+#[test]
+fn test_derive_with_mixed_fields() {
+    let m1: Mixed<u128, ByteArray> = Mixed { amount: 1, nested: G { x: 2, y: 3, z: 4 }, count: 5 };
+    let m2: Mixed<u128, ByteArray> = Mixed { amount: 6, nested: G { x: 7, y: 8, z: 9 }, count: 10 };
+    assert!(m1 + m2 == Mixed { amount: 7, nested: G { x: 9, y: 11, z: 13 }, count: 15 });
+
+    let mut m3: Mixed<u128, ByteArray> = Mixed {
+        amount: 1, nested: G { x: 1, y: 1, z: 1 }, count: 1,
+    };
+    m3 += Mixed { amount: 1, nested: G { x: 2, y: 3, z: 4 }, count: 5 };
+    assert!(m3 == Mixed { amount: 2, nested: G { x: 3, y: 4, z: 5 }, count: 6 });
+}
+
+#[test]
+fn test_newtype_derive() {
+    let w1 = Wad { value: 6 };
+    let w2 = Wad { value: 2 };
+    assert!(w1 + w2 == Wad { value: 8 });
+    assert!(Wad { value: 6 } - Wad { value: 2 } == Wad { value: 4 });
+    assert!(Wad { value: 6 } * Wad { value: 2 } == Wad { value: 12 });
+    assert!(Wad { value: 6 } / Wad { value: 2 } == Wad { value: 3 });
+
+    let mut w3 = Wad { value: 6 };
+    w3 += Wad { value: 2 };
+    w3 -= Wad { value: 1 };
+    w3 *= Wad { value: 3 };
+    w3 /= Wad { value: 7 };
+    assert!(w3 == Wad { value: 3 });
+}
+
+#[test]
+fn test_neg_derive() {
+    let s1 = S { x: 7, y: -9 };
+    assert!(-s1 == S { x: -7, y: 9 });
+}
+
+#[test]
+fn test_rem_derive() {
+    let s1 = S { x: 7, y: 9 };
+    let s2 = S { x: 4, y: 5 };
+    assert!(s1 % s2 == S { x: 3, y: 4 });
+
+    let mut s3 = S { x: 10, y: 11 };
+    s3 %= S { x: 3, y: 6 };
+    assert!(s3 == S { x: 1, y: 5 });
+}
+
+#[test]
+fn test_bitwise_derive() {
+    let f1: F<u8> = F { read: 0b1100, write: 0b1010 };
+    let f2: F<u8> = F { read: 0b1010, write: 0b0110 };
+    assert!(f1 & f2 == F { read: 0b1000, write: 0b0010 });
+
+    let f1: F<u8> = F { read: 0b1100, write: 0b1010 };
+    let f2: F<u8> = F { read: 0b1010, write: 0b0110 };
+    assert!(f1 | f2 == F { read: 0b1110, write: 0b1110 });
+
+    let f1: F<u8> = F { read: 0b1100, write: 0b1010 };
+    let f2: F<u8> = F { read: 0b1010, write: 0b0110 };
+    assert!(f1 ^ f2 == F { read: 0b0110, write: 0b1100 });
+
+    let f1: F<u8> = F { read: 0b1100, write: 0b1010 };
+    assert!(~f1 == F { read: 0b11110011, write: 0b1111111111110101 });
+}
+
+#[test]
+fn test_scalar_derive() {
+    let v = V { x: 6, y: 32 };
+    assert!(v.scalar_mul(2) == V { x: 12, y: 64 });
+    let v = V { x: 6, y: 32 };
+    assert!(v.scalar_div(2) == V { x: 3, y: 16 });
+
+    let w: W<u64> = W { v: V { x: 1, y: 2 }, t: 3 };
+    assert!(w.scalar_mul(3) == W { v: V { x: 3, y: 6 }, t: 9 });
+}
+
+#[test]
+fn test_scalar_assign_derive() {
+    let mut v = V { x: 6, y: 32 };
+    v *= 2;
+    assert!(v == V { x: 12, y: 64 });
+    v /= 4;
+    assert!(v == V { x: 3, y: 16 });
+
+    let mut w: W<u64> = W { v: V { x: 1, y: 2 }, t: 3 };
+    w *= 5;
+    assert!(w == W { v: V { x: 5, y: 10 }, t: 15 });
+}
+
+#[test]
+fn test_op_skip_and_assert_eq() {
+    let a: Position<felt252> = Position { id: 1, market: 'ETH', amount: 10, debt: 5 };
+    let b: Position<felt252> = Position { id: 2, market: 'ETH', amount: 3, debt: 2 };
+    assert!(a + b == Position { id: 1, market: 'ETH', amount: 13, debt: 7 });
+
+    let a: Position<felt252> = Position { id: 1, market: 'ETH', amount: 10, debt: 5 };
+    let b: Position<felt252> = Position { id: 2, market: 'ETH', amount: 3, debt: 2 };
+    assert!(a - b == Position { id: 1, market: 'ETH', amount: 7, debt: 3 });
+
+    let a: Position<felt252> = Position { id: 1, market: 'ETH', amount: 10, debt: 5 };
+    assert!(-a == Position { id: 1, market: 'ETH', amount: -10, debt: -5 });
+
+    let mut c: Position<felt252> = Position { id: 1, market: 'ETH', amount: 10, debt: 5 };
+    c += Position { id: 2, market: 'ETH', amount: 1, debt: 1 };
+    c -= Position { id: 3, market: 'ETH', amount: 4, debt: 2 };
+    assert!(c == Position { id: 1, market: 'ETH', amount: 7, debt: 4 });
+}
+
+#[test]
+#[should_panic]
+fn test_op_assert_eq_mismatch() {
+    let a: Position<felt252> = Position { id: 1, market: 'ETH', amount: 10, debt: 5 };
+    let b: Position<felt252> = Position { id: 1, market: 'BTC', amount: 3, debt: 2 };
+    let _c = a + b;
+}
+
+#[test]
+#[should_panic]
+fn test_op_assign_assert_eq_mismatch() {
+    let mut a: Position<felt252> = Position { id: 1, market: 'ETH', amount: 10, debt: 5 };
+    a -= Position { id: 1, market: 'BTC', amount: 3, debt: 2 };
+}
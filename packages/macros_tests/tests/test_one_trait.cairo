@@ -0,0 +1,50 @@
+use core::num::traits::One;
+
+// a basic struct
+#[derive(One, Debug, Drop, PartialEq)]
+struct B {
+    pub a: u8,
+    b: u16,
+}
+
+// a generic struct
+#[derive(One, Debug, Drop, PartialEq)]
+struct G<T1, T2> {
+    x: T1,
+    pub y: T2,
+    z: T2,
+}
+
+// a complex struct
+#[derive(One, Debug, Drop, PartialEq)]
+struct C {
+    pub g: G<u128, u256>,
+    i: u64,
+    j: u32,
+}
+
+#[test]
+fn test_one_derive() {
+    let b1: B = B { a: 1, b: 1 };
+    let b2: B = B { a: 1, b: 2 };
+
+    assert!(b1 == One::one());
+    assert!(b1.is_one());
+    assert!(b1.is_non_one() == false);
+    assert!(b2.is_one() == false);
+    assert!(b2.is_non_one());
+
+    let g1: G<u8, u128> = G { x: 1, y: 1, z: 1 };
+    let g2: G<u8, u128> = G { x: 1, y: 0, z: 1 };
+
+    assert!(g1 == One::one());
+    assert!(g1.is_one());
+    assert!(g2.is_non_one());
+
+    let c1: C = C { g: G { x: 1, y: 1, z: 1 }, i: 1, j: 1 };
+    let c2: C = C { g: G { x: 1, y: 1, z: 1 }, i: 4, j: 1 };
+
+    assert!(c1 == One::one());
+    assert!(c1.is_one());
+    assert!(c2.is_non_one());
+}
@@ -0,0 +1,77 @@
+// a basic struct, compared member by member
+#[derive(PartialOrd, Copy, Debug, Drop, PartialEq)]
+struct B {
+    a: u8,
+    b: u16,
+}
+
+// a generic struct
+#[derive(PartialOrd, Copy, Debug, Drop, PartialEq)]
+struct G<T> {
+    x: T,
+    y: u256,
+}
+
+// a struct ordered by key members
+#[derive(PartialOrd, Copy, Debug, Drop, PartialEq)]
+struct Order {
+    id: felt252,
+    #[order(key, reverse)]
+    price: u64,
+    #[order(key)]
+    time: u64,
+}
+
+// an enum ordered by variant index then payload
+#[derive(PartialOrd, Copy, Debug, Drop, PartialEq)]
+enum Priority {
+    Low,
+    Medium: u8,
+    High: u8,
+}
+
+#[test]
+fn test_partial_ord_derive() {
+    let b1 = B { a: 1, b: 5 };
+    let b2 = B { a: 1, b: 6 };
+    let b3 = B { a: 2, b: 0 };
+    assert!(b1 < b2);
+    assert!(b2 < b3);
+    assert!(b1 <= b1);
+    assert!(!(b1 < b1));
+    assert!(b3 > b1);
+    assert!(b3 >= b3);
+    assert!(!(b2 > b3));
+
+    let g1: G<i32> = G { x: -1, y: 5 };
+    let g2: G<i32> = G { x: -1, y: 4 };
+    assert!(g2 < g1);
+    assert!(g1 >= g2);
+}
+
+#[test]
+fn test_partial_ord_derive_with_keys() {
+    let expensive = Order { id: 1, price: 10, time: 2 };
+    let cheap = Order { id: 2, price: 5, time: 1 };
+    let cheap_later = Order { id: 3, price: 5, time: 3 };
+    assert!(expensive < cheap);
+    assert!(cheap < cheap_later);
+    assert!(cheap_later > expensive);
+
+    // members without `key` are ignored
+    let same = Order { id: 4, price: 5, time: 1 };
+    assert!(cheap <= same);
+    assert!(cheap >= same);
+    assert!(!(cheap < same));
+}
+
+#[test]
+fn test_partial_ord_derive_on_enums() {
+    assert!(Priority::Low < Priority::Medium(0));
+    assert!(Priority::Medium(9) < Priority::High(0));
+    assert!(Priority::High(1) < Priority::High(2));
+    assert!(Priority::High(2) >= Priority::High(2));
+    assert!(Priority::Low <= Priority::Low);
+    assert!(!(Priority::Low > Priority::Low));
+    assert!(Priority::High(0) > Priority::Low);
+}
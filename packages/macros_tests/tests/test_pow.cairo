@@ -1,4 +1,5 @@
 const TWO_TEN: u128 = pow!(2, 10);
+const TWO_EIGHTY: u128 = pow!(2, 80, u128);
 
 #[test]
 fn test_pow_macro() {
@@ -11,3 +12,33 @@ fn test_pow_macro() {
         )) == 57896044618658097711785492504343953926634992332820282019728792003956564819968_u256,
     );
 }
+
+#[test]
+fn test_typed_pow_macro() {
+    assert!(TWO_EIGHTY == 1208925819614629174706176);
+    assert!(pow!(2, 8, u16) == 256_u16);
+    assert!(pow!(2, 255, u256) == 0x8000000000000000000000000000000000000000000000000000000000000000);
+    assert!(pow!(-2, 7, i8) == -128_i8);
+    assert!(pow!(0x10, 0b10, u32) == 256_u32);
+    assert!(pow!(1_000, 2, u64) == 1000000_u64);
+}
+
+const POW2: [u256; 256] = pow_table!(base: 2, range: 0..256, ty: u256);
+const POW10: [u128; 39] = pow_table!(base: 10, range: 0..=38, ty: u128);
+
+#[test]
+fn test_pow_table_macro() {
+    let pow2 = POW2.span();
+    assert!(pow2.len() == 256);
+    assert!(*pow2[0] == 1);
+    assert!(*pow2[10] == 1024);
+    assert!(*pow2[255] == pow!(2, 255, u256));
+
+    let pow10 = POW10.span();
+    assert!(pow10.len() == 39);
+    assert!(*pow10[18] == 1000000000000000000);
+    assert!(*pow10[38] == pow!(10, 38, u128));
+
+    let odd: [i16; 3] = pow_table!(base: -3, range: 1..4, ty: i16);
+    assert!(odd.span() == [-3, 9, -27].span());
+}
@@ -15,6 +15,21 @@ struct G<T1, T2> {
     z: T2,
 }
 
+// a generic struct with its own trait bounds
+#[derive(Zero, Debug, Drop, PartialEq)]
+struct Bounded<T, +Drop<T>> {
+    x: T,
+    y: T,
+}
+
+// a generic struct with concrete fields and an unused parameter
+#[derive(Zero, Debug, Drop, PartialEq)]
+struct Mixed<T, U> {
+    amount: T,
+    nested: G<T, u64>,
+    count: u32,
+}
+
 // a complex struct
 #[derive(Zero, Debug, Drop, PartialEq)]
 struct C {
@@ -23,6 +38,28 @@ struct C {
     j: u32,
 }
 
+// an enum with a unit zero variant
+#[derive(Zero, Debug, Drop, PartialEq)]
+enum Amount {
+    #[zero]
+    Nothing,
+    Some: u64,
+}
+
+// a generic enum with a zero variant carrying a payload
+#[derive(Zero, Debug, Drop, PartialEq)]
+enum Balance<T> {
+    Locked: felt252,
+    #[zero]
+    Available: T,
+}
+
+// a single variant enum
+#[derive(Zero, Debug, Drop, PartialEq)]
+enum Single {
+    #[zero]
+    Only: u8,
+}
 
 #[test]
 fn test_zero_derive() {
@@ -53,3 +90,43 @@ fn test_zero_derive() {
     assert!(c1.is_zero() == false);
     assert!(c1.is_non_zero());
 }
+
+#[test]
+fn test_zero_derive_with_struct_bounds() {
+    let b0: Bounded<u32> = Bounded { x: 0, y: 0 };
+    let b1: Bounded<u32> = Bounded { x: 0, y: 1 };
+
+    assert!(b0 == Zero::zero());
+    assert!(b0.is_zero());
+    assert!(b1.is_non_zero());
+}
+
+#[test]
+fn test_zero_derive_with_mixed_fields() {
+    let m0: Mixed<u128, ByteArray> = Mixed { amount: 0, nested: G { x: 0, y: 0, z: 0 }, count: 0 };
+    let m1: Mixed<u128, ByteArray> = Mixed { amount: 0, nested: G { x: 0, y: 1, z: 0 }, count: 0 };
+
+    assert!(m0 == Zero::zero());
+    assert!(m0.is_zero());
+    assert!(m1.is_non_zero());
+}
+
+#[test]
+fn test_zero_derive_on_enums() {
+    assert!(Amount::Nothing == Zero::zero());
+    assert!(Amount::Nothing.is_zero());
+    assert!(Amount::Some(0).is_non_zero());
+    assert!(Amount::Some(1).is_non_zero());
+
+    let b0: Balance<u128> = Zero::zero();
+    assert!(b0 == Balance::Available(0));
+    assert!(b0.is_zero());
+    let b1: Balance<u128> = Balance::Available(1);
+    assert!(b1.is_non_zero());
+    let b2: Balance<u128> = Balance::Locked(0);
+    assert!(b2.is_non_zero());
+
+    assert!(Single::Only(0) == Zero::zero());
+    assert!(Single::Only(0).is_zero());
+    assert!(Single::Only(2).is_non_zero());
+}
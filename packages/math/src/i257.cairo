@@ -7,10 +7,11 @@ use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 /// i257 represents a 129-bit integer.
 /// The abs field holds the absolute value of the integer.
 /// The is_negative field is true for negative integers, and false for non-negative integers.
+/// The fields are public so that an i257 can be built in a constant; zero must never be negative.
 #[derive(Serde, Copy, Drop, Hash)]
 pub struct i257 {
-    abs: u256,
-    is_negative: bool,
+    pub abs: u256,
+    pub is_negative: bool,
 }
 
 #[generate_trait]